@@ -0,0 +1,157 @@
+use clap::Parser;
+use reth_db::static_file::iter_static_files;
+use reth_static_file_types::{find_fixed_range, SegmentRangeInclusive, StaticFileSegment};
+use std::{cmp::Ordering, collections::BTreeMap, fmt, path::Path};
+use tracing::info;
+
+#[derive(Parser, Debug)]
+/// The arguments for the `reth db check-static-files` command
+pub struct Command {
+    /// Only check the static files of this segment.
+    #[arg(long)]
+    segment: Option<StaticFileSegment>,
+}
+
+impl Command {
+    /// Execute `db check-static-files` command
+    pub fn execute(self, static_files_path: &Path) -> eyre::Result<()> {
+        eyre::ensure!(
+            static_files_path.is_dir(),
+            "Static files directory does not exist: {:?}",
+            static_files_path
+        );
+
+        let block_ranges = iter_static_files(static_files_path)?
+            .into_iter()
+            .filter(|(segment, _)| self.segment.map_or(true, |s| s == *segment))
+            .map(|(segment, ranges)| {
+                (segment, ranges.into_iter().map(|(block_range, _)| block_range).collect())
+            })
+            .collect::<BTreeMap<_, Vec<_>>>();
+
+        let mut issues = 0;
+        for (segment, ranges) in block_ranges {
+            let segment_issues = check_block_ranges(&ranges);
+            if let Some(issue) = segment_issues.first() {
+                println!("{segment}: {issue}");
+                println!(
+                    "  First offending static file: {}",
+                    segment.filename(&find_fixed_range(issue.block_range().start()))
+                );
+            } else {
+                let files = ranges.len();
+                info!(target: "reth::cli", %segment, files, "Static files are consistent");
+            }
+            issues += segment_issues.len();
+        }
+
+        eyre::ensure!(issues == 0, "Found {issues} inconsistent static file block ranges");
+        Ok(())
+    }
+}
+
+/// An inconsistency between the block ranges of the static files of a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StaticFileRangeIssue {
+    /// The block range does not fall within the fixed range of its first block.
+    Misaligned(SegmentRangeInclusive),
+    /// Blocks between the previous block range and this one are missing.
+    Gap { previous: SegmentRangeInclusive, next: SegmentRangeInclusive },
+    /// This block range overlaps the previous one.
+    Overlap { previous: SegmentRangeInclusive, next: SegmentRangeInclusive },
+}
+
+impl StaticFileRangeIssue {
+    /// Returns the block range of the static file that is at fault.
+    pub(crate) const fn block_range(&self) -> SegmentRangeInclusive {
+        match self {
+            Self::Misaligned(block_range) |
+            Self::Gap { next: block_range, .. } |
+            Self::Overlap { next: block_range, .. } => *block_range,
+        }
+    }
+}
+
+impl fmt::Display for StaticFileRangeIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Misaligned(block_range) => write!(
+                f,
+                "block range {block_range} does not match the fixed range {}",
+                find_fixed_range(block_range.start())
+            ),
+            Self::Gap { previous, next } => {
+                write!(f, "gap between block ranges {previous} and {next}")
+            }
+            Self::Overlap { previous, next } => {
+                write!(f, "block ranges {previous} and {next} overlap")
+            }
+        }
+    }
+}
+
+/// Checks that the given block ranges, sorted by their end, are aligned to the fixed static file
+/// ranges and follow each other without gaps or overlaps.
+pub(crate) fn check_block_ranges(ranges: &[SegmentRangeInclusive]) -> Vec<StaticFileRangeIssue> {
+    let mut issues = Vec::new();
+    let mut previous: Option<SegmentRangeInclusive> = None;
+    for range in ranges.iter().copied() {
+        let fixed_range = find_fixed_range(range.start());
+        if range.start() != fixed_range.start() || range.end() > fixed_range.end() {
+            issues.push(StaticFileRangeIssue::Misaligned(range));
+        }
+
+        if let Some(previous) = previous {
+            match range.start().cmp(&(previous.end() + 1)) {
+                Ordering::Greater => {
+                    issues.push(StaticFileRangeIssue::Gap { previous, next: range })
+                }
+                Ordering::Less => {
+                    issues.push(StaticFileRangeIssue::Overlap { previous, next: range })
+                }
+                Ordering::Equal => {}
+            }
+        }
+        previous = Some(range);
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_static_file_types::BLOCKS_PER_STATIC_FILE;
+
+    #[test]
+    fn contiguous_block_ranges() {
+        let ranges = [
+            find_fixed_range(0),
+            find_fixed_range(BLOCKS_PER_STATIC_FILE),
+            SegmentRangeInclusive::new(2 * BLOCKS_PER_STATIC_FILE, 2 * BLOCKS_PER_STATIC_FILE + 10),
+        ];
+        assert!(check_block_ranges(&ranges).is_empty());
+    }
+
+    #[test]
+    fn reports_gaps_overlaps_and_misaligned_ranges() {
+        let first = SegmentRangeInclusive::new(0, 100);
+        let second = find_fixed_range(BLOCKS_PER_STATIC_FILE);
+        assert_eq!(
+            check_block_ranges(&[first, second]),
+            vec![StaticFileRangeIssue::Gap { previous: first, next: second }]
+        );
+
+        let misaligned = SegmentRangeInclusive::new(50, BLOCKS_PER_STATIC_FILE - 1);
+        assert_eq!(
+            check_block_ranges(&[SegmentRangeInclusive::new(0, 100), misaligned]),
+            vec![
+                StaticFileRangeIssue::Misaligned(misaligned),
+                StaticFileRangeIssue::Overlap {
+                    previous: SegmentRangeInclusive::new(0, 100),
+                    next: misaligned
+                },
+            ]
+        );
+        assert_eq!(check_block_ranges(&[first, misaligned])[0].block_range(), misaligned);
+    }
+}
@@ -4,6 +4,7 @@ use reth_db::version::{get_db_version, DatabaseVersionError, DB_VERSION};
 use reth_db_common::DbTool;
 use std::io::{self, Write};
 
+mod check_static_files;
 mod checksum;
 mod clear;
 mod diff;
@@ -32,6 +33,9 @@ pub enum Subcommands {
     List(list::Command),
     /// Calculates the content checksum of a table
     Checksum(checksum::Command),
+    /// Checks that the block ranges of the static files are contiguous and aligned to the fixed
+    /// static file ranges
+    CheckStaticFiles(check_static_files::Command),
     /// Create a diff between two database tables or two entire databases.
     Diff(diff::Command),
     /// Gets the content of a table for the given key
@@ -94,6 +98,9 @@ impl Command {
                     command.execute(&tool)?;
                 });
             }
+            Subcommands::CheckStaticFiles(command) => {
+                command.execute(&static_files_path)?;
+            }
             Subcommands::Diff(command) => {
                 db_ro_exec!(self.env, tool, {
                     command.execute(&tool)?;
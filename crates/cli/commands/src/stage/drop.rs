@@ -1,17 +1,61 @@
 //! Database debugging tool
 use crate::common::{AccessRights, Environment, EnvironmentArgs};
 use clap::Parser;
+use human_bytes::human_bytes;
 use itertools::Itertools;
-use reth_db::{static_file::iter_static_files, tables};
-use reth_db_api::transaction::DbTxMut;
+use reth_config::Config;
+use reth_db::{
+    init_db, mdbx, static_file::iter_static_files, tables, version::db_version_file_path,
+    DatabaseEnv, TableViewer, Tables,
+};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbCursorRW, DbDupCursorRW},
+    database::Database,
+    table::{DupSort, Table},
+    transaction::{DbTx, DbTxMut},
+};
 use reth_db_common::{
-    init::{insert_genesis_header, insert_genesis_history, insert_genesis_state},
+    init::{
+        insert_account_history, insert_genesis_header, insert_genesis_state, insert_storage_history,
+    },
     DbTool,
 };
+use reth_downloaders::bodies::noop::NoopBodiesDownloader;
+use reth_evm::noop::NoopBlockExecutorProvider;
+use reth_exex::ExExManagerHandle;
 use reth_node_core::args::StageEnum;
-use reth_provider::{writer::UnifiedStorageWriter, StaticFileProviderFactory};
-use reth_stages::StageId;
-use reth_static_file_types::{find_fixed_range, StaticFileSegment};
+use reth_primitives::BlockNumber;
+use reth_provider::{
+    providers::{StaticFileJarProvider, StaticFileProvider, StaticFileWriter},
+    writer::UnifiedStorageWriter,
+    HeaderProvider, ProviderFactory, ReceiptProvider, StageCheckpointReader,
+    StaticFileProviderFactory, TransactionsProvider,
+};
+use reth_prune::PruneModes;
+use reth_stages::{
+    stages::{
+        AccountHashingStage, BodyStage, ExecutionStage, IndexAccountHistoryStage,
+        IndexStorageHistoryStage, MerkleStage, SenderRecoveryStage, StorageHashingStage,
+        TransactionLookupStage,
+    },
+    Pipeline, StageId,
+};
+use reth_static_file::{
+    segments::{self, Segment},
+    StaticFileProducer,
+};
+use reth_static_file_types::{find_fixed_range, SegmentRangeInclusive, StaticFileSegment};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    collections::BTreeSet,
+    io::{self, IsTerminal, Write},
+    ops::RangeBounds,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
+use tracing::{info, warn};
 
 /// `reth drop-stage` command
 #[derive(Debug, Parser)]
@@ -19,29 +63,527 @@ pub struct Command {
     #[command(flatten)]
     env: EnvironmentArgs,
 
-    stage: StageEnum,
+    /// The stages to drop, separated by commas or spaces.
+    ///
+    /// All stages are dropped within a single database transaction.
+    #[arg(value_delimiter = ',', num_args = 1.., required = true)]
+    stages: Vec<StageEnum>,
+
+    /// Archive the data of the dropped stages into static files in the given directory before
+    /// clearing them.
+    ///
+    /// Only supported for stages backed by a static file segment (headers, bodies and execution),
+    /// other stages are dropped without archiving. The existing static files of the segment are
+    /// copied and any data still in the database is appended, so the directory needs roughly as
+    /// much free space as the segment occupies today.
+    #[arg(long, value_name = "DIR")]
+    archive_to_static_files: Option<PathBuf>,
+
+    /// Skip the interactive confirmation before dropping.
+    ///
+    /// Required when stdin is not a terminal, otherwise the command refuses to drop anything.
+    #[arg(short, long, alias = "force", short_alias = 'f')]
+    yes: bool,
+
+    /// Perform the drop on a copy of the database and static files instead of the originals.
+    ///
+    /// The copy is created in a temporary directory inside the datadir, the drop is performed and
+    /// verified on it, and the original data is left untouched. The node must not be running while
+    /// the copy is made.
+    #[arg(long, conflicts_with = "archive_to_static_files")]
+    on_copy: bool,
+
+    /// Keep the copy created by `--on-copy` instead of deleting it after verification
+    #[arg(long, requires = "on_copy")]
+    keep_copy: bool,
+
+    /// Rebuild the static file of the stage's segment that holds the given block range from the
+    /// database tables instead of dropping the stage.
+    ///
+    /// Requires exactly one stage.
+    ///
+    /// The range is given as `START..=END` and must match the blocks held by a single static
+    /// file, e.g. `0..=499999`. The static file is rebuilt in a temporary directory inside the
+    /// datadir and only replaces the existing one after it was read back and verified. Fails if
+    /// the database does not contain the full range.
+    #[arg(
+        long,
+        value_name = "RANGE",
+        value_parser = parse_block_range,
+        conflicts_with_all = ["archive_to_static_files", "on_copy"]
+    )]
+    rebuild_jar: Option<SegmentRangeInclusive>,
+
+    /// Log the tables that would be cleared with their number of entries and the static files
+    /// that would be deleted, without changing anything.
+    #[arg(long, conflicts_with_all = ["archive_to_static_files", "on_copy", "rebuild_jar"])]
+    dry_run: bool,
+
+    /// Compare the highest block held by the static files of each stage's segment with the stage
+    /// checkpoint and report any mismatch, without dropping anything.
+    ///
+    /// Only applies to stages backed by a static file segment (headers, bodies and execution), and
+    /// skips execution receipts if they are pruned. Fails if the static files are behind the stage
+    /// checkpoint.
+    #[arg(
+        long,
+        conflicts_with_all = ["archive_to_static_files", "on_copy", "rebuild_jar", "dry_run"]
+    )]
+    check_consistency: bool,
+
+    /// Only drop the data after the given block by unwinding the stages to it, instead of
+    /// clearing their tables.
+    ///
+    /// Uses the unwind logic of the pipeline and is only supported for stages that can be unwound
+    /// offline: bodies, senders, execution, hashing, merkle, tx-lookup and the history stages.
+    /// Dropping bodies for a range also drops the senders of the transactions in that range.
+    #[arg(
+        long,
+        value_name = "BLOCK",
+        conflicts_with_all = [
+            "archive_to_static_files",
+            "on_copy",
+            "rebuild_jar",
+            "dry_run",
+            "check_consistency"
+        ]
+    )]
+    from: Option<BlockNumber>,
+
+    /// The highest block expected to be dropped with `--from`.
+    ///
+    /// Fails without unwinding anything if the checkpoint of any of the stages is above it.
+    #[arg(long, value_name = "BLOCK", requires = "from")]
+    to: Option<BlockNumber>,
+
+    /// Back up the tables and static files of the dropped stages into the given directory before
+    /// clearing them.
+    ///
+    /// The tables are copied into a new database in `<DIR>/db` and the static files of the
+    /// stages' segments are copied as-is into `<DIR>/static_files`, so the directory needs as much
+    /// free space as the dropped data occupies today. Fails if `<DIR>/db` already exists.
+    #[arg(
+        long,
+        value_name = "DIR",
+        conflicts_with_all = ["on_copy", "rebuild_jar", "dry_run", "check_consistency", "from"]
+    )]
+    backup: Option<PathBuf>,
+
+    /// Report the space freed in each cleared table after dropping the stages.
+    ///
+    /// MDBX can't compact individual tables. Pages freed by clearing a table are moved to the
+    /// freelist of the database and reused by later writes, so the database file does not shrink.
+    /// Shrinking the file requires compacting the whole database.
+    #[arg(long, conflicts_with_all = ["on_copy", "rebuild_jar", "dry_run", "check_consistency", "from"])]
+    report_freed_space: bool,
 }
 
 impl Command {
     /// Execute `db` command
     pub async fn execute(self) -> eyre::Result<()> {
+        if let Some(block_range) = self.rebuild_jar {
+            let [stage] = self.stages[..] else {
+                eyre::bail!("--rebuild-jar requires exactly one stage")
+            };
+            return self.rebuild_static_file(stage, block_range)
+        }
+
+        if self.check_consistency {
+            return self.check_static_file_consistency()
+        }
+
+        let stages = expand_dependent_stages(&self.stages);
+        let dependents = stages.iter().filter(|stage| !self.stages.contains(stage)).join(", ");
+        if !dependents.is_empty() {
+            println!("Dropping {} will also reset: {dependents}", self.stages.iter().join(", "));
+        }
+
+        // Dropping on a copy or in dry-run mode leaves the original data untouched
+        if !self.yes && !self.on_copy && !self.dry_run && !self.confirm_drop(&stages)? {
+            println!("Stage drop aborted!");
+            return Ok(())
+        }
+
+        if self.on_copy {
+            return self.execute_on_copy(&stages)
+        }
+
+        if let Some(from) = self.from {
+            return self.unwind_stages(&stages, from)
+        }
+
         let Environment { provider_factory, .. } = self.env.init(AccessRights::RW)?;
 
+        let static_files_path = provider_factory.static_file_provider().directory().to_path_buf();
+        if let Some(pending) = PendingDrop::load(&static_files_path)? {
+            if self.dry_run {
+                warn!(target: "reth::cli", ?pending, "A previous drop was interrupted and is finished by the next drop");
+            } else {
+                info!(target: "reth::cli", ?pending, "Finishing a previously interrupted drop");
+                self.finish_pending_drop(&provider_factory, pending)?;
+            }
+        }
+
+        if let Some(archive_dir) = &self.archive_to_static_files {
+            let static_file_segments = stages
+                .iter()
+                .filter(|stage| self.stages.contains(stage))
+                .filter_map(|stage| stage_static_file_segment(*stage))
+                .collect::<Vec<_>>();
+            if static_file_segments.is_empty() {
+                eyre::bail!(
+                    "Stages {} have no static file segment to archive",
+                    self.stages.iter().join(", ")
+                )
+            }
+            for static_file_segment in static_file_segments {
+                archive_static_file_segment(&provider_factory, static_file_segment, archive_dir)?;
+            }
+        }
+
+        if let Some(backup_dir) = &self.backup {
+            self.backup_stages(&provider_factory, &stages, backup_dir)?;
+        }
+
+        if !self.report_freed_space {
+            return self.drop_stages(provider_factory, &stages)
+        }
+
+        let tables = stages.iter().flat_map(|stage| stage_tables(*stage)).unique().collect_vec();
+        let tool = DbTool::new(provider_factory.clone())?;
+        let table_usages = || {
+            tables
+                .iter()
+                .map(|table| table.view(&TableUsageViewer(&tool)))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let usages_before = table_usages()?;
+        let freelist_before = mdbx_freelist_size(tool.provider_factory.db_ref())?;
+        self.drop_stages(provider_factory, &stages)?;
+        let usages_after = table_usages()?;
+        let freelist_after = mdbx_freelist_size(tool.provider_factory.db_ref())?;
+
+        let mut total_freed = 0;
+        for ((table, (entries_before, size_before)), (entries_after, size_after)) in
+            tables.iter().zip(usages_before).zip(usages_after)
+        {
+            let freed = size_before.saturating_sub(size_after);
+            total_freed += freed;
+            println!(
+                "Table {} freed {} and {} entries",
+                table.name(),
+                human_bytes(freed as f64),
+                entries_before.saturating_sub(entries_after)
+            );
+        }
+        println!(
+            "Freed {} in total, the freelist grew by {}",
+            human_bytes(total_freed as f64),
+            human_bytes(freelist_after.saturating_sub(freelist_before) as f64)
+        );
+
+        Ok(())
+    }
+
+    /// Compares the highest block held by the static files of each stage's segment with the stage
+    /// checkpoint and fails if any of them are behind it.
+    ///
+    /// Static files ahead of the checkpoint are only reported, since they are healed on the next
+    /// node startup.
+    fn check_static_file_consistency(&self) -> eyre::Result<()> {
+        let Environment { provider_factory, config, .. } = self.env.init(AccessRights::RO)?;
+        let prune_modes = config.prune.map(|prune| prune.segments).unwrap_or_default();
+        let provider = provider_factory.provider()?;
+        let static_files = iter_static_files(provider_factory.static_file_provider().directory())?;
+
+        let mut mismatches = Vec::new();
+        // Only the requested stages, without duplicates
+        for stage in expand_dependent_stages(&self.stages)
+            .into_iter()
+            .filter(|stage| self.stages.contains(stage))
+        {
+            let Some(segment) = stage_static_file_segment(stage) else {
+                println!("Stage {stage} has no static file segment, skipping");
+                continue
+            };
+            if segment.is_receipts() && prune_modes.has_receipts_pruning() {
+                // Receipts are written to the database instead of static files if they are pruned
+                println!("Receipts of stage {stage} are pruned and kept in the database, skipping");
+                continue
+            }
+
+            let highest_static_file_block = static_files
+                .get(&segment)
+                .and_then(|files| files.iter().map(|(block_range, _)| block_range.end()).max())
+                .unwrap_or_default();
+            // Stages backed by a static file segment have a single checkpoint
+            let stage_id = stage_checkpoint_ids(stage)[0];
+            let checkpoint_block =
+                provider.get_stage_checkpoint(stage_id)?.unwrap_or_default().block_number;
+
+            match highest_static_file_block.cmp(&checkpoint_block) {
+                Ordering::Equal => println!(
+                    "Stage {stage} is consistent: static files of segment {segment} and the stage checkpoint are at block {checkpoint_block}"
+                ),
+                Ordering::Greater => println!(
+                    "Static files of segment {segment} are ahead of stage {stage} at block {highest_static_file_block}, the stage checkpoint is at block {checkpoint_block}. They are healed on the next node startup"
+                ),
+                Ordering::Less => {
+                    println!("Stage {stage} is inconsistent: static files of segment {segment} are behind at block {highest_static_file_block}, the stage checkpoint is at block {checkpoint_block}");
+                    mismatches.push(stage);
+                }
+            }
+        }
+
+        eyre::ensure!(
+            mismatches.is_empty(),
+            "Static files are inconsistent with the checkpoints of stages {}",
+            mismatches.iter().join(", ")
+        );
+        Ok(())
+    }
+
+    /// Checks the stage checkpoints against `--to` and drops the data of the given stages after
+    /// block `from`.
+    fn unwind_stages(&self, stages: &[StageEnum], from: BlockNumber) -> eyre::Result<()> {
+        if stages.contains(&StageEnum::Headers) {
+            eyre::bail!("Stage {} can't be dropped for a block range", StageEnum::Headers)
+        }
+        if let Some(to) = self.to {
+            eyre::ensure!(from <= to, "--from {from} is above --to {to}");
+        }
+
+        let Environment { provider_factory, config, .. } = self.env.init(AccessRights::RW)?;
+
+        if let Some(to) = self.to {
+            let provider = provider_factory.provider()?;
+            for stage_id in stages.iter().flat_map(|stage| stage_checkpoint_ids(*stage)) {
+                let checkpoint =
+                    provider.get_stage_checkpoint(*stage_id)?.unwrap_or_default().block_number;
+                eyre::ensure!(
+                    checkpoint <= to,
+                    "Stage checkpoint {stage_id} is at block {checkpoint}, above --to {to}"
+                );
+            }
+        }
+
+        unwind_stages_with_pipeline(provider_factory, &config, stages, from)
+    }
+
+    /// Asks the user to confirm dropping the given stages, naming the chain and datadir.
+    ///
+    /// Fails if stdin is not a terminal, so scripts have to pass `--yes` explicitly.
+    fn confirm_drop(&self, stages: &[StageEnum]) -> eyre::Result<bool> {
+        eyre::ensure!(
+            io::stdin().is_terminal(),
+            "Refusing to drop stages without confirmation because stdin is not a terminal, pass --yes to drop them"
+        );
+
+        let data_dir = self.env.datadir.clone().resolve_datadir(self.env.chain.chain);
+        print!(
+            "Drop {} of chain {} in {}? This can't be undone. (y/N): ",
+            stages.iter().join(", "),
+            self.env.chain.chain,
+            data_dir.data_dir().display()
+        );
+        // Flush the buffer to ensure the message is printed immediately
+        io::stdout().flush()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        Ok(input.trim().eq_ignore_ascii_case("y"))
+    }
+
+    /// Copies the tables of the given stages into a new database in `<backup_dir>/db` and their
+    /// static files into `<backup_dir>/static_files`.
+    fn backup_stages<DB: Database>(
+        &self,
+        provider_factory: &ProviderFactory<DB>,
+        stages: &[StageEnum],
+        backup_dir: &Path,
+    ) -> eyre::Result<()> {
         let static_file_provider = provider_factory.static_file_provider();
+        let backup_db_path = backup_dir.join("db");
+        let backup_static_files_path = backup_dir.join("static_files");
+        eyre::ensure!(
+            !backup_db_path.exists(),
+            "Backup database already exists: {:?}",
+            backup_db_path
+        );
+        eyre::ensure!(
+            backup_static_files_path != static_file_provider.directory(),
+            "Backup directory must differ from the static files directory"
+        );
 
-        let tool = DbTool::new(provider_factory)?;
+        let backup_db = init_db(&backup_db_path, self.env.db.database_args())?;
+        let tx = provider_factory.db_ref().tx()?;
+        for table in stages.iter().flat_map(|stage| stage_tables(*stage)).unique() {
+            table.view(&BackupViewer { tx: &tx, backup_db: &backup_db })?;
+        }
+        drop(tx);
+
+        reth_fs_util::create_dir_all(&backup_static_files_path)?;
+        for segment in stages.iter().copied().filter_map(stage_static_file_segment) {
+            copy_static_files(
+                static_file_provider.directory(),
+                segment,
+                &backup_static_files_path,
+            )?;
+        }
+
+        info!(target: "reth::cli", stages = %stages.iter().join(", "), path = ?backup_dir, "Backed up stages");
+
+        Ok(())
+    }
+
+    /// Copies the database and static files into a temporary directory, drops the stages on the
+    /// copy and verifies the result.
+    fn execute_on_copy(&self, stages: &[StageEnum]) -> eyre::Result<()> {
+        let data_dir = self.env.datadir.clone().resolve_datadir(self.env.chain.chain);
+        let db_path = data_dir.db();
+        let static_files_path = data_dir.static_files();
+        eyre::ensure!(db_path.is_dir(), "Database does not exist: {:?}", db_path);
+
+        let Environment { provider_factory, .. } = self.env.init(AccessRights::RO)?;
+
+        let copy_dir =
+            tempfile::Builder::new().prefix("reth-drop-stage-").tempdir_in(data_dir.data_dir())?;
+        let copy_db_path = copy_dir.path().join("db");
+        let copy_static_files_path = copy_dir.path().join("static_files");
+        info!(target: "reth::cli", path = ?copy_dir.path(), "Copying database and static files");
+        // MDBX copies the database within a read transaction, so the copy is a consistent snapshot
+        // even if a node writes to the database meanwhile. Static files are copied as they are.
+        reth_fs_util::create_dir_all(&copy_db_path)?;
+        provider_factory.db_ref().copy(&copy_db_path.join(MDBX_DATA_FILE_NAME), false)?;
+        reth_fs_util::copy(db_version_file_path(&db_path), db_version_file_path(&copy_db_path))?;
+        copy_dir_files(&static_files_path, &copy_static_files_path)?;
+        drop(provider_factory);
+
+        let copy_provider_factory = ProviderFactory::new(
+            Arc::new(init_db(&copy_db_path, self.env.db.database_args())?),
+            self.env.chain.clone(),
+            StaticFileProvider::read_write(&copy_static_files_path)?,
+        );
+        self.drop_stages(copy_provider_factory.clone(), stages)?;
+
+        let verification = verify_dropped_stages(&copy_provider_factory, stages);
+        drop(copy_provider_factory);
+
+        let copy_path = if self.keep_copy { Some(copy_dir.into_path()) } else { None };
+        let requested = self.stages.iter().join(", ");
+        match &verification {
+            Ok(()) => println!("Dropping {requested} on a copy succeeded and was verified"),
+            Err(err) => println!("Dropping {requested} on a copy failed verification: {err}"),
+        }
+        if let Some(copy_path) = copy_path {
+            println!("The copy was kept at {}", copy_path.display());
+        }
 
-        let static_file_segment = match self.stage {
-            StageEnum::Headers => Some(StaticFileSegment::Headers),
-            StageEnum::Bodies => Some(StaticFileSegment::Transactions),
-            StageEnum::Execution => Some(StaticFileSegment::Receipts),
-            _ => None,
+        verification
+    }
+
+    /// Rebuilds the static file of the stage's segment that holds the given block range from the
+    /// database tables and replaces the existing static file with it.
+    fn rebuild_static_file(
+        &self,
+        stage: StageEnum,
+        block_range: SegmentRangeInclusive,
+    ) -> eyre::Result<()> {
+        let Some(segment) = stage_static_file_segment(stage) else {
+            eyre::bail!("Stage {stage} has no static file segment to rebuild")
         };
+        let fixed_range = find_fixed_range(block_range.start());
+        eyre::ensure!(
+            block_range.start() == fixed_range.start() && block_range.end() <= fixed_range.end(),
+            "Block range {block_range} is not held by a single static file, the static file starting at block {} ends at block {} at most",
+            fixed_range.start(),
+            fixed_range.end()
+        );
+
+        let Environment { provider_factory, .. } = self.env.init(AccessRights::RW)?;
+        let static_file_provider = provider_factory.static_file_provider();
+        let file_name = segment.filename(&fixed_range);
+        let static_file_path = static_file_provider.directory().join(&file_name);
+
+        // The header of a corrupt static file may still be readable, in which case the rebuilt
+        // static file has to hold exactly the same blocks and transactions
+        let existing_tx_range = match static_file_provider.get_segment_provider(
+            segment,
+            || None,
+            Some(static_file_path.as_path()),
+        ) {
+            Ok(Some(existing)) => {
+                let existing_range = existing.user_header().block_range().copied();
+                eyre::ensure!(
+                    existing_range == Some(block_range),
+                    "Static file {file_name} holds blocks {existing_range:?}, not {block_range}"
+                );
+                Some(existing.user_header().tx_range().copied())
+            }
+            _ => {
+                warn!(target: "reth::cli", path = ?static_file_path, "Existing static file can't be read, rebuilding it from the given block range");
+                None
+            }
+        };
+
+        let provider = provider_factory.provider()?;
+        ensure_range_in_database(provider.tx_ref(), segment, block_range)?;
+
+        let data_dir = self.env.datadir.clone().resolve_datadir(self.env.chain.chain);
+        let rebuild_dir =
+            tempfile::Builder::new().prefix("reth-rebuild-jar-").tempdir_in(data_dir.data_dir())?;
+        info!(target: "reth::cli", ?segment, %block_range, path = ?rebuild_dir.path(), "Rebuilding static file");
+
+        let rebuild_provider = StaticFileProvider::read_write(rebuild_dir.path())?;
+        let (_, segment_impl) = static_file_segment_impl(segment);
+        segment_impl.copy_to_static_files(
+            provider_factory.provider()?,
+            rebuild_provider.clone(),
+            block_range.into(),
+        )?;
+        rebuild_provider.commit()?;
+        drop(rebuild_provider);
+
+        // Read the rebuilt static file back before it replaces the existing one
+        let rebuilt_provider = StaticFileProvider::read_only(rebuild_dir.path())?;
+        let rebuilt = rebuilt_provider
+            .get_segment_provider(
+                segment,
+                || None,
+                Some(rebuild_dir.path().join(&file_name).as_path()),
+            )?
+            .ok_or_else(|| eyre::eyre!("Rebuilt static file {file_name} is missing"))?;
+        verify_rebuilt_static_file(&rebuilt, provider.tx_ref(), segment, block_range)?;
+        if let Some(existing_tx_range) = existing_tx_range {
+            let rebuilt_tx_range = rebuilt.user_header().tx_range().copied();
+            eyre::ensure!(
+                rebuilt_tx_range == existing_tx_range,
+                "Rebuilt static file {file_name} holds transactions {rebuilt_tx_range:?}, expected {existing_tx_range:?}"
+            );
+        }
+        drop(rebuilt);
+        drop(rebuilt_provider);
+
+        replace_static_file(rebuild_dir.path(), static_file_provider.directory(), &file_name)?;
+        info!(target: "reth::cli", ?segment, %block_range, path = ?static_file_path, "Rebuilt static file");
+
+        Ok(())
+    }
+
+    /// Drops the given stages, which must contain all of their dependent stages.
+    fn drop_stages<DB: Database>(
+        &self,
+        provider_factory: ProviderFactory<DB>,
+        stages: &[StageEnum],
+    ) -> eyre::Result<()> {
+        let static_file_provider = provider_factory.static_file_provider();
+
+        let tool = DbTool::new(provider_factory)?;
 
-        // Delete static file segment data before inserting the genesis header below
-        if let Some(static_file_segment) = static_file_segment {
-            let static_file_provider = tool.provider_factory.static_file_provider();
-            let static_files = iter_static_files(static_file_provider.directory())?;
+        // Static files are deleted after the database changes were committed
+        let mut pending = PendingDrop::default();
+        let static_files = iter_static_files(static_file_provider.directory())?;
+        for static_file_segment in stages.iter().copied().filter_map(stage_static_file_segment) {
             if let Some(segment_static_files) = static_files.get(&static_file_segment) {
                 // Delete static files from the highest to the lowest block range
                 for (block_range, _) in segment_static_files
@@ -49,8 +591,12 @@ impl Command {
                     .sorted_by_key(|(block_range, _)| block_range.start())
                     .rev()
                 {
-                    static_file_provider
-                        .delete_jar(static_file_segment, find_fixed_range(block_range.start()))?;
+                    if self.dry_run {
+                        info!(target: "reth::cli", segment = %static_file_segment, %block_range, "Would delete static file");
+                    }
+                    pending
+                        .static_files
+                        .push((static_file_segment, find_fixed_range(block_range.start())));
                 }
             }
         }
@@ -58,124 +604,1172 @@ impl Command {
         let provider_rw = tool.provider_factory.provider_rw()?;
         let tx = provider_rw.tx_ref();
 
-        match self.stage {
+        // The genesis header is inserted once after all stages have been cleared and the static
+        // files were deleted, since inserting it twice would append it to the static files twice.
+        let mut reinsert_genesis_header = false;
+        for stage in stages {
+            let started_at = Instant::now();
+            let mut cleared = 0;
+            match *stage {
+                StageEnum::Headers => {
+                    cleared += clear_table::<tables::CanonicalHeaders>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::Headers>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::HeaderTerminalDifficulties>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::HeaderNumbers>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::Headers.to_string(),
+                        Default::default(),
+                    )?;
+                    reinsert_genesis_header = true;
+                }
+                StageEnum::Bodies => {
+                    cleared += clear_table::<tables::BlockBodyIndices>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::Transactions>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::TransactionBlocks>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::BlockOmmers>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::BlockWithdrawals>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::BlockRequests>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::Bodies.to_string(),
+                        Default::default(),
+                    )?;
+                    reinsert_genesis_header = true;
+                }
+                StageEnum::Senders => {
+                    cleared += clear_table::<tables::TransactionSenders>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::SenderRecovery.to_string(),
+                        Default::default(),
+                    )?;
+                }
+                StageEnum::Execution => {
+                    cleared += clear_table::<tables::PlainAccountState>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::PlainStorageState>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::AccountChangeSets>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::StorageChangeSets>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::Bytecodes>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::Receipts>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::Execution.to_string(),
+                        Default::default(),
+                    )?;
+                    if !self.dry_run {
+                        let alloc = &self.env.chain.genesis().alloc;
+                        insert_genesis_state(&provider_rw, alloc.len(), alloc.iter())?;
+                    }
+                }
+                StageEnum::AccountHashing => {
+                    cleared += clear_table::<tables::HashedAccounts>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::AccountHashing.to_string(),
+                        Default::default(),
+                    )?;
+                }
+                StageEnum::StorageHashing => {
+                    cleared += clear_table::<tables::HashedStorages>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::StorageHashing.to_string(),
+                        Default::default(),
+                    )?;
+                }
+                StageEnum::Hashing => {
+                    // Clear hashed accounts
+                    cleared += clear_table::<tables::HashedAccounts>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::AccountHashing.to_string(),
+                        Default::default(),
+                    )?;
+
+                    // Clear hashed storages
+                    cleared += clear_table::<tables::HashedStorages>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::StorageHashing.to_string(),
+                        Default::default(),
+                    )?;
+                }
+                StageEnum::Merkle => {
+                    cleared += clear_table::<tables::AccountsTrie>(tx, self.dry_run)?;
+                    cleared += clear_table::<tables::StoragesTrie>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::MerkleExecute.to_string(),
+                        Default::default(),
+                    )?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::MerkleUnwind.to_string(),
+                        Default::default(),
+                    )?;
+                    tx.delete::<tables::StageCheckpointProgresses>(
+                        StageId::MerkleExecute.to_string(),
+                        None,
+                    )?;
+                }
+                StageEnum::AccountHistory => {
+                    cleared += clear_table::<tables::AccountsHistory>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::IndexAccountHistory.to_string(),
+                        Default::default(),
+                    )?;
+                    if !self.dry_run {
+                        insert_account_history(
+                            &provider_rw,
+                            self.env.chain.genesis.alloc.iter(),
+                            0,
+                        )?;
+                    }
+                }
+                StageEnum::StorageHistory => {
+                    cleared += clear_table::<tables::StoragesHistory>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::IndexStorageHistory.to_string(),
+                        Default::default(),
+                    )?;
+                    if !self.dry_run {
+                        insert_storage_history(
+                            &provider_rw,
+                            self.env.chain.genesis.alloc.iter(),
+                            0,
+                        )?;
+                    }
+                }
+                StageEnum::TxLookup => {
+                    cleared += clear_table::<tables::TransactionHashNumbers>(tx, self.dry_run)?;
+                    tx.put::<tables::StageCheckpoints>(
+                        StageId::TransactionLookup.to_string(),
+                        Default::default(),
+                    )?;
+                    reinsert_genesis_header = true;
+                }
+            }
+
+            if self.dry_run {
+                info!(target: "reth::cli", %stage, entries = cleared, "Would drop stage");
+            } else {
+                info!(target: "reth::cli", %stage, entries = cleared, elapsed = ?started_at.elapsed(), "Dropped stage");
+            }
+        }
+
+        if self.dry_run {
+            // Checkpoint resets written above are discarded together with the transaction
+            provider_rw.into_tx().abort();
+            info!(target: "reth::cli", "Dry run finished, nothing was changed");
+            return Ok(())
+        }
+
+        tx.put::<tables::StageCheckpoints>(StageId::Finish.to_string(), Default::default())?;
+
+        // Record the remaining work before committing, so that an interrupted drop can be resumed
+        pending.reinsert_genesis_header = reinsert_genesis_header;
+        let static_files_path = static_file_provider.directory().to_path_buf();
+        pending.save(&static_files_path)?;
+        if let Err(err) = UnifiedStorageWriter::commit_unwind(provider_rw, static_file_provider) {
+            // Nothing was cleared, so the static files must not be deleted by a later drop
+            PendingDrop::remove(&static_files_path)?;
+            return Err(err.into())
+        }
+
+        self.finish_pending_drop(&tool.provider_factory, pending)?;
+
+        verify_static_file_checkpoints(&tool.provider_factory, stages)
+    }
+
+    /// Deletes the static files of a drop whose database changes were already committed and
+    /// re-inserts the genesis header if needed.
+    ///
+    /// Static files that no longer exist are skipped, so an interrupted drop can be finished by
+    /// calling this again with the same [`PendingDrop`].
+    ///
+    /// Refuses to delete anything unless the checkpoints of the stages writing the pending static
+    /// file segments are reset, since otherwise the database changes were never committed.
+    fn finish_pending_drop<DB: Database>(
+        &self,
+        provider_factory: &ProviderFactory<DB>,
+        pending: PendingDrop,
+    ) -> eyre::Result<()> {
+        let static_file_provider = provider_factory.static_file_provider();
+        let directory = static_file_provider.directory().to_path_buf();
+
+        let provider = provider_factory.provider()?;
+        for segment in pending.static_files.iter().map(|(segment, _)| *segment).unique() {
+            let stage_id = static_file_segment_stage(segment);
+            let checkpoint =
+                provider.get_stage_checkpoint(stage_id)?.unwrap_or_default().block_number;
+            eyre::ensure!(
+                checkpoint == 0,
+                "A pending drop would delete static files of segment {segment}, but stage checkpoint {stage_id} is at block {checkpoint}, so the database was not cleared. Remove {:?} if the drop was aborted",
+                directory.join(PENDING_DROP_FILE_NAME)
+            );
+        }
+        drop(provider);
+
+        for (index, (segment, fixed_range)) in pending.static_files.iter().enumerate() {
+            if !directory.join(segment.filename(fixed_range)).exists() {
+                continue
+            }
+            if let Err(err) = static_file_provider.delete_jar(*segment, *fixed_range) {
+                let remaining = pending.static_files[index..]
+                    .iter()
+                    .map(|(segment, fixed_range)| segment.filename(fixed_range))
+                    .join(", ");
+                eyre::bail!(
+                    "The database was already cleared, but deleting static files failed: {err}. Remaining static files: {remaining}. Run the command again to delete them"
+                )
+            }
+        }
+
+        if pending.reinsert_genesis_header {
+            let provider_rw = provider_factory.provider_rw()?;
+            insert_genesis_header(&provider_rw, &static_file_provider, self.env.chain.clone())?;
+            UnifiedStorageWriter::commit(provider_rw, static_file_provider)?;
+        }
+
+        PendingDrop::remove(&directory)
+    }
+}
+
+/// The name of the file in the static files directory that records the work left for a drop after
+/// its database changes were committed.
+const PENDING_DROP_FILE_NAME: &str = "drop-stage-pending.json";
+
+/// The static files to delete and whether to re-insert the genesis header after the database
+/// changes of a drop were committed.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PendingDrop {
+    /// The static files to delete, by segment and fixed block range.
+    static_files: Vec<(StaticFileSegment, SegmentRangeInclusive)>,
+    /// Whether the genesis header is inserted after the static files were deleted.
+    reinsert_genesis_header: bool,
+}
+
+impl PendingDrop {
+    /// Loads the pending drop recorded in the given static files directory, if any.
+    fn load(directory: &Path) -> eyre::Result<Option<Self>> {
+        let path = directory.join(PENDING_DROP_FILE_NAME);
+        if !path.exists() {
+            return Ok(None)
+        }
+        Ok(Some(serde_json::from_str(&reth_fs_util::read_to_string(path)?)?))
+    }
+
+    /// Records the pending drop in the given static files directory.
+    fn save(&self, directory: &Path) -> eyre::Result<()> {
+        reth_fs_util::write(directory.join(PENDING_DROP_FILE_NAME), serde_json::to_vec(self)?)?;
+        Ok(())
+    }
+
+    /// Removes the pending drop recorded in the given static files directory.
+    fn remove(directory: &Path) -> eyre::Result<()> {
+        reth_fs_util::remove_file(directory.join(PENDING_DROP_FILE_NAME))?;
+        Ok(())
+    }
+}
+
+/// Clears the given table, or only logs its number of entries if `dry_run` is set.
+///
+/// Returns the number of entries the table held.
+fn clear_table<T: Table>(tx: &(impl DbTx + DbTxMut), dry_run: bool) -> eyre::Result<usize> {
+    let entries = tx.entries::<T>()?;
+    if dry_run {
+        info!(target: "reth::cli", table = T::NAME, entries, "Would clear table");
+        return Ok(entries)
+    }
+
+    // Clearing a large table can take minutes, so log it before it starts
+    info!(target: "reth::cli", table = T::NAME, entries, "Clearing table");
+    let started_at = Instant::now();
+    tx.clear::<T>()?;
+    info!(target: "reth::cli", table = T::NAME, elapsed = ?started_at.elapsed(), "Cleared table");
+    Ok(entries)
+}
+
+/// Returns the tables that are cleared when dropping the given stage.
+const fn stage_tables(stage: StageEnum) -> &'static [Tables] {
+    match stage {
+        StageEnum::Headers => &[
+            Tables::CanonicalHeaders,
+            Tables::Headers,
+            Tables::HeaderTerminalDifficulties,
+            Tables::HeaderNumbers,
+        ],
+        StageEnum::Bodies => &[
+            Tables::BlockBodyIndices,
+            Tables::Transactions,
+            Tables::TransactionBlocks,
+            Tables::BlockOmmers,
+            Tables::BlockWithdrawals,
+            Tables::BlockRequests,
+        ],
+        StageEnum::Senders => &[Tables::TransactionSenders],
+        StageEnum::Execution => &[
+            Tables::PlainAccountState,
+            Tables::PlainStorageState,
+            Tables::AccountChangeSets,
+            Tables::StorageChangeSets,
+            Tables::Bytecodes,
+            Tables::Receipts,
+        ],
+        StageEnum::AccountHashing => &[Tables::HashedAccounts],
+        StageEnum::StorageHashing => &[Tables::HashedStorages],
+        StageEnum::Hashing => &[Tables::HashedAccounts, Tables::HashedStorages],
+        StageEnum::Merkle => &[Tables::AccountsTrie, Tables::StoragesTrie],
+        StageEnum::AccountHistory => &[Tables::AccountsHistory],
+        StageEnum::StorageHistory => &[Tables::StoragesHistory],
+        StageEnum::TxLookup => &[Tables::TransactionHashNumbers],
+    }
+}
+
+/// Copies all entries of a table into the same table of a backup database.
+struct BackupViewer<'a, TX, DB> {
+    tx: &'a TX,
+    backup_db: &'a DB,
+}
+
+impl<TX: DbTx, DB: Database> TableViewer<()> for BackupViewer<'_, TX, DB> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<(), Self::Error> {
+        let backup_tx = self.backup_db.tx_mut()?;
+        let mut backup_cursor = backup_tx.cursor_write::<T>()?;
+        for entry in self.tx.cursor_read::<T>()?.walk(None)? {
+            let (key, value) = entry?;
+            backup_cursor.append(key, value)?;
+        }
+        drop(backup_cursor);
+        backup_tx.commit()?;
+        info!(target: "reth::cli", table = T::NAME, "Backed up table");
+        Ok(())
+    }
+
+    fn view_dupsort<T: DupSort>(&self) -> Result<(), Self::Error> {
+        let backup_tx = self.backup_db.tx_mut()?;
+        let mut backup_cursor = backup_tx.cursor_dup_write::<T>()?;
+        for entry in self.tx.cursor_read::<T>()?.walk(None)? {
+            let (key, value) = entry?;
+            backup_cursor.append_dup(key, value)?;
+        }
+        drop(backup_cursor);
+        backup_tx.commit()?;
+        info!(target: "reth::cli", table = T::NAME, "Backed up table");
+        Ok(())
+    }
+}
+
+/// Reads the number of entries and the size in bytes of a table.
+struct TableUsageViewer<'a>(&'a DbTool<Arc<DatabaseEnv>>);
+
+impl TableViewer<(usize, usize)> for TableUsageViewer<'_> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<(usize, usize), Self::Error> {
+        Ok((self.0.table_entries::<T>()?, self.0.table_size_bytes::<T>()?))
+    }
+}
+
+/// Returns the size of the MDBX freelist in bytes.
+fn mdbx_freelist_size(db: &DatabaseEnv) -> eyre::Result<usize> {
+    db.view(|tx| {
+        let page_size = tx.inner.db_stat(&mdbx::Database::freelist_db())?.page_size() as usize;
+        Ok::<_, eyre::Report>(tx.inner.env().freelist()? * page_size)
+    })?
+}
+
+/// Returns the static file segment that holds the data of the given stage, if any.
+const fn stage_static_file_segment(stage: StageEnum) -> Option<StaticFileSegment> {
+    match stage {
+        StageEnum::Headers => Some(StaticFileSegment::Headers),
+        StageEnum::Bodies => Some(StaticFileSegment::Transactions),
+        StageEnum::Execution => Some(StaticFileSegment::Receipts),
+        _ => None,
+    }
+}
+
+/// Returns the stage that writes the data of the given [`StaticFileSegment`].
+const fn static_file_segment_stage(segment: StaticFileSegment) -> StageId {
+    match segment {
+        StaticFileSegment::Headers => StageId::Headers,
+        StaticFileSegment::Transactions => StageId::Bodies,
+        StaticFileSegment::Receipts => StageId::Execution,
+    }
+}
+
+/// Returns the stage that writes the data of the given [`StaticFileSegment`] and the [`Segment`]
+/// that copies it from the database tables into static files.
+fn static_file_segment_impl<DB: Database>(
+    segment: StaticFileSegment,
+) -> (StageId, Box<dyn Segment<DB>>) {
+    let segment_impl: Box<dyn Segment<DB>> = match segment {
+        StaticFileSegment::Headers => Box::new(segments::Headers),
+        StaticFileSegment::Transactions => Box::new(segments::Transactions),
+        StaticFileSegment::Receipts => Box::new(segments::Receipts),
+    };
+    (static_file_segment_stage(segment), segment_impl)
+}
+
+/// Returns the stages that directly consume the data written by the given stage.
+///
+/// Hashing stages are represented by [`StageEnum::Hashing`], since dropping it resets both of its
+/// sub-stages.
+const fn direct_dependent_stages(stage: StageEnum) -> &'static [StageEnum] {
+    match stage {
+        StageEnum::Headers => &[StageEnum::Bodies],
+        StageEnum::Bodies => &[StageEnum::Senders, StageEnum::TxLookup],
+        StageEnum::Senders => &[StageEnum::Execution],
+        StageEnum::Execution => {
+            &[StageEnum::Hashing, StageEnum::AccountHistory, StageEnum::StorageHistory]
+        }
+        StageEnum::AccountHashing | StageEnum::StorageHashing | StageEnum::Hashing => {
+            &[StageEnum::Merkle]
+        }
+        StageEnum::Merkle |
+        StageEnum::TxLookup |
+        StageEnum::AccountHistory |
+        StageEnum::StorageHistory => &[],
+    }
+}
+
+/// Drops the data of the given stages after block `from` by unwinding them with a pipeline that
+/// only consists of these stages.
+fn unwind_stages_with_pipeline<DB: Database + 'static>(
+    provider_factory: ProviderFactory<DB>,
+    config: &Config,
+    stages: &[StageEnum],
+    from: BlockNumber,
+) -> eyre::Result<()> {
+    let includes = |stage| stages.contains(&stage);
+    let stage_conf = &config.stages;
+    let etl_config = stage_conf.etl.clone();
+    let prune_modes = config.prune.clone().map(|prune| prune.segments).unwrap_or_default();
+
+    // Stages are added in pipeline order, so the pipeline unwinds them in reverse
+    let mut builder = Pipeline::builder();
+    if includes(StageEnum::Bodies) {
+        // Unwinding does not download anything
+        builder = builder.add_stage(BodyStage::new(NoopBodiesDownloader::default()));
+    }
+    if includes(StageEnum::Senders) {
+        builder = builder.add_stage(SenderRecoveryStage::new(stage_conf.sender_recovery));
+    }
+    if includes(StageEnum::Execution) {
+        // Unwinding does not require a valid executor
+        builder = builder.add_stage(ExecutionStage::new(
+            NoopBlockExecutorProvider::default(),
+            stage_conf.execution.into(),
+            stage_conf.execution_external_clean_threshold(),
+            prune_modes.clone(),
+            ExExManagerHandle::empty(),
+        ));
+    }
+    if includes(StageEnum::Merkle) {
+        builder = builder.add_stage(MerkleStage::default_unwind());
+    }
+    if includes(StageEnum::AccountHashing) || includes(StageEnum::Hashing) {
+        builder = builder
+            .add_stage(AccountHashingStage::new(stage_conf.account_hashing, etl_config.clone()));
+    }
+    if includes(StageEnum::StorageHashing) || includes(StageEnum::Hashing) {
+        builder = builder
+            .add_stage(StorageHashingStage::new(stage_conf.storage_hashing, etl_config.clone()));
+    }
+    if includes(StageEnum::Merkle) {
+        builder = builder.add_stage(MerkleStage::new_execution(stage_conf.merkle.clean_threshold));
+    }
+    if includes(StageEnum::TxLookup) {
+        builder = builder.add_stage(TransactionLookupStage::new(
+            stage_conf.transaction_lookup,
+            etl_config.clone(),
+            prune_modes.transaction_lookup,
+        ));
+    }
+    if includes(StageEnum::StorageHistory) {
+        builder = builder.add_stage(IndexStorageHistoryStage::new(
+            stage_conf.index_storage_history,
+            etl_config.clone(),
+            prune_modes.storage_history,
+        ));
+    }
+    if includes(StageEnum::AccountHistory) {
+        builder = builder.add_stage(IndexAccountHistoryStage::new(
+            stage_conf.index_account_history,
+            etl_config,
+            prune_modes.account_history,
+        ));
+    }
+
+    let mut pipeline = builder.build(
+        provider_factory.clone(),
+        StaticFileProducer::new(provider_factory, PruneModes::default()),
+    );
+    info!(target: "reth::cli", stages = %stages.iter().join(", "), from, "Unwinding stages");
+    pipeline.unwind(from, None)?;
+
+    Ok(())
+}
+
+/// Returns the given stages without duplicates, followed by all stages that transitively depend
+/// on them in pipeline order.
+fn expand_dependent_stages(stages: &[StageEnum]) -> Vec<StageEnum> {
+    let mut requested = Vec::with_capacity(stages.len());
+    for stage in stages {
+        if !requested.contains(stage) {
+            requested.push(*stage);
+        }
+    }
+
+    let mut dependents = BTreeSet::new();
+    let mut queue = requested
+        .iter()
+        .flat_map(|stage| direct_dependent_stages(*stage))
+        .copied()
+        .collect::<Vec<_>>();
+    while let Some(dependent) = queue.pop() {
+        if dependents.insert(dependent) {
+            queue.extend_from_slice(direct_dependent_stages(dependent));
+        }
+    }
+    dependents.retain(|dependent| !requested.contains(dependent));
+
+    requested.into_iter().chain(dependents).collect()
+}
+
+/// Returns the ids of the stage checkpoints that are reset when dropping the given stage.
+const fn stage_checkpoint_ids(stage: StageEnum) -> &'static [StageId] {
+    match stage {
+        StageEnum::Headers => &[StageId::Headers],
+        StageEnum::Bodies => &[StageId::Bodies],
+        StageEnum::Senders => &[StageId::SenderRecovery],
+        StageEnum::Execution => &[StageId::Execution],
+        StageEnum::AccountHashing => &[StageId::AccountHashing],
+        StageEnum::StorageHashing => &[StageId::StorageHashing],
+        StageEnum::Hashing => &[StageId::AccountHashing, StageId::StorageHashing],
+        StageEnum::Merkle => &[StageId::MerkleExecute, StageId::MerkleUnwind],
+        StageEnum::TxLookup => &[StageId::TransactionLookup],
+        StageEnum::AccountHistory => &[StageId::IndexAccountHistory],
+        StageEnum::StorageHistory => &[StageId::IndexStorageHistory],
+    }
+}
+
+/// Verifies that the given stages were dropped: their checkpoints are reset, the tables that are
+/// not re-initialized with genesis data are empty and their static files only hold genesis data.
+fn verify_dropped_stages<DB: Database>(
+    provider_factory: &ProviderFactory<DB>,
+    stages: &[StageEnum],
+) -> eyre::Result<()> {
+    let provider = provider_factory.provider()?;
+    let tx = provider.tx_ref();
+
+    for stage in stages {
+        for stage_id in stage_checkpoint_ids(*stage) {
+            let checkpoint = provider.get_stage_checkpoint(*stage_id)?.unwrap_or_default();
+            eyre::ensure!(
+                checkpoint.block_number == 0,
+                "Stage checkpoint {stage_id} was not reset, it is at block {}",
+                checkpoint.block_number
+            );
+        }
+
+        let entries = match stage {
             StageEnum::Headers => {
-                tx.clear::<tables::CanonicalHeaders>()?;
-                tx.clear::<tables::Headers>()?;
-                tx.clear::<tables::HeaderTerminalDifficulties>()?;
-                tx.clear::<tables::HeaderNumbers>()?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::Headers.to_string(),
-                    Default::default(),
-                )?;
-                insert_genesis_header(&provider_rw, &static_file_provider, self.env.chain)?;
+                tx.entries::<tables::CanonicalHeaders>()? + tx.entries::<tables::Headers>()?
             }
             StageEnum::Bodies => {
-                tx.clear::<tables::BlockBodyIndices>()?;
-                tx.clear::<tables::Transactions>()?;
-                tx.clear::<tables::TransactionBlocks>()?;
-                tx.clear::<tables::BlockOmmers>()?;
-                tx.clear::<tables::BlockWithdrawals>()?;
-                tx.clear::<tables::BlockRequests>()?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::Bodies.to_string(),
-                    Default::default(),
-                )?;
-                insert_genesis_header(&provider_rw, &static_file_provider, self.env.chain)?;
-            }
-            StageEnum::Senders => {
-                tx.clear::<tables::TransactionSenders>()?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::SenderRecovery.to_string(),
-                    Default::default(),
-                )?;
-            }
-            StageEnum::Execution => {
-                tx.clear::<tables::PlainAccountState>()?;
-                tx.clear::<tables::PlainStorageState>()?;
-                tx.clear::<tables::AccountChangeSets>()?;
-                tx.clear::<tables::StorageChangeSets>()?;
-                tx.clear::<tables::Bytecodes>()?;
-                tx.clear::<tables::Receipts>()?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::Execution.to_string(),
-                    Default::default(),
-                )?;
-                let alloc = &self.env.chain.genesis().alloc;
-                insert_genesis_state(&provider_rw, alloc.len(), alloc.iter())?;
-            }
-            StageEnum::AccountHashing => {
-                tx.clear::<tables::HashedAccounts>()?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::AccountHashing.to_string(),
-                    Default::default(),
-                )?;
-            }
-            StageEnum::StorageHashing => {
-                tx.clear::<tables::HashedStorages>()?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::StorageHashing.to_string(),
-                    Default::default(),
-                )?;
+                tx.entries::<tables::Transactions>()? + tx.entries::<tables::TransactionBlocks>()?
             }
+            StageEnum::Senders => tx.entries::<tables::TransactionSenders>()?,
+            StageEnum::Execution => tx.entries::<tables::Receipts>()?,
+            StageEnum::AccountHashing => tx.entries::<tables::HashedAccounts>()?,
+            StageEnum::StorageHashing => tx.entries::<tables::HashedStorages>()?,
             StageEnum::Hashing => {
-                // Clear hashed accounts
-                tx.clear::<tables::HashedAccounts>()?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::AccountHashing.to_string(),
-                    Default::default(),
-                )?;
-
-                // Clear hashed storages
-                tx.clear::<tables::HashedStorages>()?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::StorageHashing.to_string(),
-                    Default::default(),
-                )?;
+                tx.entries::<tables::HashedAccounts>()? + tx.entries::<tables::HashedStorages>()?
             }
             StageEnum::Merkle => {
-                tx.clear::<tables::AccountsTrie>()?;
-                tx.clear::<tables::StoragesTrie>()?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::MerkleExecute.to_string(),
-                    Default::default(),
-                )?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::MerkleUnwind.to_string(),
-                    Default::default(),
-                )?;
-                tx.delete::<tables::StageCheckpointProgresses>(
-                    StageId::MerkleExecute.to_string(),
-                    None,
-                )?;
-            }
-            StageEnum::AccountHistory | StageEnum::StorageHistory => {
-                tx.clear::<tables::AccountsHistory>()?;
-                tx.clear::<tables::StoragesHistory>()?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::IndexAccountHistory.to_string(),
-                    Default::default(),
-                )?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::IndexStorageHistory.to_string(),
-                    Default::default(),
-                )?;
-                insert_genesis_history(&provider_rw, self.env.chain.genesis.alloc.iter())?;
-            }
-            StageEnum::TxLookup => {
-                tx.clear::<tables::TransactionHashNumbers>()?;
-                tx.put::<tables::StageCheckpoints>(
-                    StageId::TransactionLookup.to_string(),
-                    Default::default(),
-                )?;
-                insert_genesis_header(&provider_rw, &static_file_provider, self.env.chain)?;
+                tx.entries::<tables::AccountsTrie>()? + tx.entries::<tables::StoragesTrie>()?
             }
+            StageEnum::TxLookup => tx.entries::<tables::TransactionHashNumbers>()?,
+            // History tables are re-initialized with the genesis history
+            StageEnum::AccountHistory | StageEnum::StorageHistory => 0,
+        };
+        eyre::ensure!(entries == 0, "Tables of stage {stage} still contain {entries} entries");
+
+        if let Some(segment) = stage_static_file_segment(*stage) {
+            let highest_block =
+                provider_factory.static_file_provider().get_highest_static_file_block(segment);
+            eyre::ensure!(
+                highest_block.unwrap_or_default() == 0,
+                "Static files of segment {segment} still contain blocks up to {highest_block:?}"
+            );
         }
+    }
 
-        tx.put::<tables::StageCheckpoints>(StageId::Finish.to_string(), Default::default())?;
+    Ok(())
+}
 
-        UnifiedStorageWriter::commit_unwind(provider_rw, static_file_provider)?;
+/// Verifies that the checkpoint of each of the given stages that is backed by a static file segment
+/// is at or below the highest block still held by the segment's static files.
+///
+/// A checkpoint above it means that static files were deleted while the checkpoint was left stale.
+fn verify_static_file_checkpoints<DB: Database>(
+    provider_factory: &ProviderFactory<DB>,
+    stages: &[StageEnum],
+) -> eyre::Result<()> {
+    let provider = provider_factory.provider()?;
+    let static_file_provider = provider_factory.static_file_provider();
 
-        Ok(())
+    for stage in stages {
+        let Some(segment) = stage_static_file_segment(*stage) else { continue };
+        let highest_block = static_file_provider.get_highest_static_file_block(segment);
+
+        for stage_id in stage_checkpoint_ids(*stage) {
+            let checkpoint =
+                provider.get_stage_checkpoint(*stage_id)?.unwrap_or_default().block_number;
+            info!(target: "reth::cli", %stage_id, %segment, checkpoint, ?highest_block, "Reconciled stage checkpoint with static files");
+            eyre::ensure!(
+                checkpoint <= highest_block.unwrap_or_default(),
+                "Stage checkpoint {stage_id} is at block {checkpoint}, above the highest block {highest_block:?} of the static files of segment {segment}"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// The name of the MDBX data file in the database directory.
+const MDBX_DATA_FILE_NAME: &str = "mdbx.dat";
+
+/// Copies all files of the `from` directory into the `to` directory.
+fn copy_dir_files(from: &Path, to: &Path) -> eyre::Result<()> {
+    reth_fs_util::create_dir_all(to)?;
+    for entry in reth_fs_util::read_dir(from)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue
+        }
+        reth_fs_util::copy(entry.path(), to.join(entry.file_name()))?;
+    }
+    Ok(())
+}
+
+/// Copies the existing static files of the given [`StaticFileSegment`] from `from` into `to`,
+/// including their offsets and configuration.
+fn copy_static_files(from: &Path, segment: StaticFileSegment, to: &Path) -> eyre::Result<()> {
+    let static_files = iter_static_files(from)?;
+    if let Some(segment_static_files) = static_files.get(&segment) {
+        for (block_range, _) in segment_static_files {
+            let file_name = segment.filename(&find_fixed_range(block_range.start()));
+            for entry in reth_fs_util::read_dir(from)? {
+                let entry = entry?;
+                let entry_name = entry.file_name().to_string_lossy().into_owned();
+                if entry_name == file_name || entry_name.starts_with(&format!("{file_name}.")) {
+                    reth_fs_util::copy(entry.path(), to.join(entry_name))?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Archives all data of the given [`StaticFileSegment`] into static files in `archive_dir`.
+///
+/// Existing static files of the segment are copied as-is, while data that has not been moved to
+/// static files yet is appended from the database tables using the static file writer. The archive
+/// is opened afterwards to verify that it can be read back.
+fn archive_static_file_segment<DB: Database>(
+    provider_factory: &ProviderFactory<DB>,
+    segment: StaticFileSegment,
+    archive_dir: &Path,
+) -> eyre::Result<()> {
+    let static_file_provider = provider_factory.static_file_provider();
+    if archive_dir == static_file_provider.directory() {
+        eyre::bail!("Archive directory must differ from the static files directory")
+    }
+    reth_fs_util::create_dir_all(archive_dir)?;
+
+    copy_static_files(static_file_provider.directory(), segment, archive_dir)?;
+
+    // Append the data that is still only present in the database tables
+    let (stage_id, segment_impl) = static_file_segment_impl(segment);
+    let highest_static_file_block = static_file_provider.get_highest_static_file_block(segment);
+    let start = highest_static_file_block.map_or(0, |block| block + 1);
+    let end = provider_factory
+        .provider()?
+        .get_stage_checkpoint(stage_id)?
+        .map(|checkpoint| checkpoint.block_number)
+        .unwrap_or_default();
+
+    let archive_provider = StaticFileProvider::read_write(archive_dir)?;
+    if start <= end {
+        segment_impl.copy_to_static_files(
+            provider_factory.provider()?,
+            archive_provider.clone(),
+            start..=end,
+        )?;
+        archive_provider.commit()?;
+    }
+    drop(archive_provider);
+
+    // Verify that the archive can be read back and holds at least the existing static file data
+    let archived_block =
+        StaticFileProvider::read_only(archive_dir)?.get_highest_static_file_block(segment);
+    if archived_block < highest_static_file_block {
+        eyre::bail!(
+            "Archived {segment} static files end at block {archived_block:?}, expected at least {highest_static_file_block:?}"
+        )
+    }
+
+    info!(target: "reth::cli", ?segment, ?archived_block, path = ?archive_dir, "Archived static file segment");
+
+    Ok(())
+}
+
+/// Parses a block range given as `START..=END`.
+fn parse_block_range(value: &str) -> eyre::Result<SegmentRangeInclusive> {
+    let (start, end) = value
+        .split_once("..=")
+        .ok_or_else(|| eyre::eyre!("expected a block range like `0..=499999`"))?;
+    let (start, end) = (start.trim().parse::<u64>()?, end.trim().parse::<u64>()?);
+    eyre::ensure!(start <= end, "block range start {start} is after its end {end}");
+    Ok(SegmentRangeInclusive::new(start, end))
+}
+
+/// Returns the number of entries of the table within the given key range.
+fn count_entries<T: Table<Key = u64>>(
+    tx: &impl DbTx,
+    range: impl RangeBounds<u64>,
+) -> eyre::Result<u64> {
+    Ok(tx.cursor_read::<T>()?.walk_range(range)?.count() as u64)
+}
+
+/// Ensures that the database tables hold all data of the given [`StaticFileSegment`] within the
+/// block range.
+fn ensure_range_in_database(
+    tx: &impl DbTx,
+    segment: StaticFileSegment,
+    block_range: SegmentRangeInclusive,
+) -> eyre::Result<()> {
+    let range = block_range.start()..=block_range.end();
+    let blocks = block_range.end() - block_range.start() + 1;
+
+    let mut counts = Vec::new();
+    match segment {
+        StaticFileSegment::Headers => {
+            counts.push(("headers", blocks, count_entries::<tables::Headers>(tx, range.clone())?));
+            counts.push((
+                "header total difficulties",
+                blocks,
+                count_entries::<tables::HeaderTerminalDifficulties>(tx, range.clone())?,
+            ));
+            counts.push((
+                "canonical headers",
+                blocks,
+                count_entries::<tables::CanonicalHeaders>(tx, range)?,
+            ));
+        }
+        StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
+            counts.push((
+                "block body indices",
+                blocks,
+                count_entries::<tables::BlockBodyIndices>(tx, range)?,
+            ));
+
+            let first = tx.get::<tables::BlockBodyIndices>(block_range.start())?;
+            let last = tx.get::<tables::BlockBodyIndices>(block_range.end())?;
+            if let (Some(first), Some(last)) = (first, last) {
+                let tx_range = first.first_tx_num()..last.next_tx_num();
+                let txs = tx_range.end.saturating_sub(tx_range.start);
+                let (name, entries) = if segment == StaticFileSegment::Transactions {
+                    ("transactions", count_entries::<tables::Transactions>(tx, tx_range)?)
+                } else {
+                    ("receipts", count_entries::<tables::Receipts>(tx, tx_range)?)
+                };
+                counts.push((name, txs, entries));
+            }
+        }
+    }
+
+    for (name, expected, entries) in counts {
+        eyre::ensure!(
+            entries == expected,
+            "Database only holds {entries} of the {expected} {name} in block range {block_range}"
+        );
+    }
+
+    Ok(())
+}
+
+/// Verifies that the rebuilt static file holds the given block range and that its first and last
+/// entries match the database.
+fn verify_rebuilt_static_file(
+    rebuilt: &StaticFileJarProvider<'_>,
+    tx: &impl DbTx,
+    segment: StaticFileSegment,
+    block_range: SegmentRangeInclusive,
+) -> eyre::Result<()> {
+    let rebuilt_range = rebuilt.user_header().block_range().copied();
+    eyre::ensure!(
+        rebuilt_range == Some(block_range),
+        "Rebuilt static file holds blocks {rebuilt_range:?}, expected {block_range}"
+    );
+
+    match segment {
+        StaticFileSegment::Headers => {
+            for block in [block_range.start(), block_range.end()] {
+                eyre::ensure!(
+                    rebuilt.header_by_number(block)? == tx.get::<tables::Headers>(block)?,
+                    "Header {block} of the rebuilt static file does not match the database"
+                );
+            }
+        }
+        StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
+            let Some(tx_range) = rebuilt.user_header().tx_range().copied() else { return Ok(()) };
+            for tx_num in [tx_range.start(), tx_range.end()] {
+                let matches = if segment == StaticFileSegment::Transactions {
+                    rebuilt.transaction_by_id(tx_num)?.map(|transaction| transaction.hash()) ==
+                        tx.get::<tables::Transactions>(tx_num)?
+                            .map(|transaction| transaction.hash())
+                } else {
+                    rebuilt.receipt(tx_num)? == tx.get::<tables::Receipts>(tx_num)?
+                };
+                eyre::ensure!(
+                    matches,
+                    "Transaction {tx_num} of the rebuilt {segment} static file does not match the database"
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies the files of the static file `file_name` from the `from` directory into the `to`
+/// directory, replacing the existing ones.
+fn replace_static_file(from: &Path, to: &Path, file_name: &str) -> eyre::Result<()> {
+    let is_static_file_entry =
+        |name: &str| name == file_name || name.starts_with(&format!("{file_name}."));
+
+    let mut replaced = BTreeSet::new();
+    for entry in reth_fs_util::read_dir(from)? {
+        let entry = entry?;
+        let entry_name = entry.file_name().to_string_lossy().into_owned();
+        if is_static_file_entry(&entry_name) {
+            reth_fs_util::copy(entry.path(), to.join(&entry_name))?;
+            replaced.insert(entry_name);
+        }
+    }
+
+    // Remove files of the old static file that the rebuilt one does not have
+    for entry in reth_fs_util::read_dir(to)? {
+        let entry = entry?;
+        let entry_name = entry.file_name().to_string_lossy().into_owned();
+        if is_static_file_entry(&entry_name) && !replaced.contains(&entry_name) {
+            reth_fs_util::remove_file(entry.path())?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_db_api::models::StoredBlockBodyIndices;
+    use reth_db_common::init::init_genesis;
+    use reth_primitives::{Address, Header, TransactionSignedNoHash, U256};
+    use reth_provider::{
+        test_utils::create_test_provider_factory_with_chain_spec, BlockHashReader,
+    };
+    use reth_stages::StageCheckpoint;
+
+    #[test]
+    fn expands_dependent_stages_in_pipeline_order() {
+        assert_eq!(expand_dependent_stages(&[StageEnum::Merkle]), vec![StageEnum::Merkle]);
+        assert_eq!(
+            expand_dependent_stages(&[StageEnum::Senders]),
+            vec![
+                StageEnum::Senders,
+                StageEnum::Execution,
+                StageEnum::Hashing,
+                StageEnum::Merkle,
+                StageEnum::AccountHistory,
+                StageEnum::StorageHistory,
+            ]
+        );
+        assert_eq!(
+            expand_dependent_stages(&[StageEnum::Bodies]),
+            vec![
+                StageEnum::Bodies,
+                StageEnum::Senders,
+                StageEnum::Execution,
+                StageEnum::Hashing,
+                StageEnum::Merkle,
+                StageEnum::TxLookup,
+                StageEnum::AccountHistory,
+                StageEnum::StorageHistory,
+            ]
+        );
+    }
+
+    #[test]
+    fn expands_dependent_stages_of_multiple_stages() {
+        assert_eq!(
+            expand_dependent_stages(&[
+                StageEnum::TxLookup,
+                StageEnum::Merkle,
+                StageEnum::Hashing,
+                StageEnum::TxLookup
+            ]),
+            vec![StageEnum::TxLookup, StageEnum::Merkle, StageEnum::Hashing]
+        );
+        assert_eq!(
+            expand_dependent_stages(&[StageEnum::Hashing, StageEnum::AccountHistory]),
+            vec![StageEnum::Hashing, StageEnum::AccountHistory, StageEnum::Merkle]
+        );
+    }
+
+    #[test]
+    fn parses_multiple_stages() {
+        let command = Command::try_parse_from(["reth", "headers,merkle", "tx-lookup"]).unwrap();
+        assert_eq!(
+            command.stages,
+            vec![StageEnum::Headers, StageEnum::Merkle, StageEnum::TxLookup]
+        );
+        assert!(Command::try_parse_from(["reth"]).is_err());
+    }
+
+    #[test]
+    fn parses_yes_and_force_alias() {
+        for flag in ["-y", "--yes", "-f", "--force"] {
+            assert!(Command::try_parse_from(["reth", "execution", flag]).unwrap().yes);
+        }
+        assert!(!Command::try_parse_from(["reth", "execution"]).unwrap().yes);
+    }
+
+    #[test]
+    fn parses_unwind_range() {
+        let command =
+            Command::try_parse_from(["reth", "execution", "--from", "100", "--to", "200"]).unwrap();
+        assert_eq!((command.from, command.to), (Some(100), Some(200)));
+        assert!(Command::try_parse_from(["reth", "execution", "--to", "200"]).is_err());
+    }
+
+    #[test]
+    fn parses_block_range() {
+        let range = parse_block_range("500000..=999999").unwrap();
+        assert_eq!((range.start(), range.end()), (500_000, 999_999));
+        assert!(parse_block_range("500000-999999").is_err());
+        assert!(parse_block_range("10..=1").is_err());
+    }
+
+    #[test]
+    fn pending_drop_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(PendingDrop::load(dir.path()).unwrap().is_none());
+
+        let fixed_range = find_fixed_range(0);
+        PendingDrop {
+            static_files: vec![(StaticFileSegment::Headers, fixed_range)],
+            reinsert_genesis_header: true,
+        }
+        .save(dir.path())
+        .unwrap();
+
+        let pending = PendingDrop::load(dir.path()).unwrap().unwrap();
+        assert_eq!(pending.static_files, vec![(StaticFileSegment::Headers, fixed_range)]);
+        assert!(pending.reinsert_genesis_header);
+
+        PendingDrop::remove(dir.path()).unwrap();
+        assert!(PendingDrop::load(dir.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn backup_copies_stage_tables_and_static_files() {
+        let backup_dir = tempfile::tempdir().unwrap();
+        let command = Command::try_parse_from([
+            "reth",
+            "headers",
+            "--chain",
+            "dev",
+            "--backup",
+            backup_dir.path().to_str().unwrap(),
+        ])
+        .unwrap();
+        let provider_factory =
+            create_test_provider_factory_with_chain_spec(command.env.chain.clone());
+        let genesis_hash = init_genesis(provider_factory.clone()).unwrap();
+
+        let stages = expand_dependent_stages(&command.stages);
+        command.backup_stages(&provider_factory, &stages, backup_dir.path()).unwrap();
+
+        let backup_db = reth_db::open_db_read_only(
+            &backup_dir.path().join("db"),
+            command.env.db.database_args(),
+        )
+        .unwrap();
+        let tx = backup_db.tx().unwrap();
+        assert_eq!(tx.get::<tables::HeaderNumbers>(genesis_hash).unwrap(), Some(0));
+        assert_eq!(tx.entries::<tables::HashedAccounts>().unwrap(), 0);
+
+        let static_files = iter_static_files(&backup_dir.path().join("static_files")).unwrap();
+        assert!(static_files.contains_key(&StaticFileSegment::Headers));
+
+        // A second backup into the same directory is refused
+        assert!(command.backup_stages(&provider_factory, &stages, backup_dir.path()).is_err());
+    }
+
+    #[test]
+    fn drop_headers_keeps_only_genesis() {
+        let command = Command::try_parse_from(["reth", "headers", "--chain", "dev"]).unwrap();
+        let provider_factory =
+            create_test_provider_factory_with_chain_spec(command.env.chain.clone());
+        let genesis_hash = init_genesis(provider_factory.clone()).unwrap();
+
+        // Headers above genesis, both in static files and in the database tables
+        let provider_rw = provider_factory.provider_rw().unwrap();
+        let static_file_provider = provider_factory.static_file_provider();
+        {
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            for number in 1..=3 {
+                let header = Header { number, ..Default::default() };
+                let hash = header.hash_slow();
+                writer.append_header(&header, U256::ZERO, &hash).unwrap();
+
+                let tx = provider_rw.tx_ref();
+                tx.put::<tables::CanonicalHeaders>(number, hash).unwrap();
+                tx.put::<tables::Headers>(number, header).unwrap();
+                tx.put::<tables::HeaderTerminalDifficulties>(number, U256::ZERO.into()).unwrap();
+                tx.put::<tables::HeaderNumbers>(hash, number).unwrap();
+            }
+        }
+        provider_rw
+            .tx_ref()
+            .put::<tables::StageCheckpoints>(StageId::Headers.to_string(), StageCheckpoint::new(3))
+            .unwrap();
+        UnifiedStorageWriter::commit(provider_rw, static_file_provider).unwrap();
+
+        let stages = expand_dependent_stages(&command.stages);
+        command.drop_stages(provider_factory.clone(), &stages).unwrap();
+        verify_dropped_stages(&provider_factory, &stages).unwrap();
+        assert!(PendingDrop::load(provider_factory.static_file_provider().directory())
+            .unwrap()
+            .is_none());
+
+        let provider = provider_factory.provider().unwrap();
+        let tx = provider.tx_ref();
+        assert_eq!(tx.entries::<tables::CanonicalHeaders>().unwrap(), 0);
+        assert_eq!(tx.entries::<tables::Headers>().unwrap(), 0);
+        assert_eq!(tx.entries::<tables::HeaderTerminalDifficulties>().unwrap(), 0);
+        assert_eq!(
+            tx.cursor_read::<tables::HeaderNumbers>()
+                .unwrap()
+                .walk(None)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![(genesis_hash, 0)]
+        );
+        assert_eq!(
+            provider_factory
+                .static_file_provider()
+                .get_highest_static_file_block(StaticFileSegment::Headers),
+            Some(0)
+        );
+        assert_eq!(provider.block_hash(0).unwrap(), Some(genesis_hash));
+        assert_eq!(
+            provider.get_stage_checkpoint(StageId::Headers).unwrap(),
+            Some(Default::default())
+        );
+    }
+
+    #[test]
+    fn pending_drop_is_refused_without_reset_checkpoint() {
+        let command = Command::try_parse_from(["reth", "headers", "--chain", "dev"]).unwrap();
+        let provider_factory =
+            create_test_provider_factory_with_chain_spec(command.env.chain.clone());
+        init_genesis(provider_factory.clone()).unwrap();
+
+        let provider_rw = provider_factory.provider_rw().unwrap();
+        provider_rw
+            .tx_ref()
+            .put::<tables::StageCheckpoints>(StageId::Headers.to_string(), StageCheckpoint::new(3))
+            .unwrap();
+        provider_rw.commit().unwrap();
+
+        // A drop that recorded its pending static files, but whose database changes were lost
+        let pending = PendingDrop {
+            static_files: vec![(StaticFileSegment::Headers, find_fixed_range(0))],
+            reinsert_genesis_header: true,
+        };
+        assert!(command.finish_pending_drop(&provider_factory, pending).is_err());
+        assert_eq!(
+            provider_factory
+                .static_file_provider()
+                .get_highest_static_file_block(StaticFileSegment::Headers),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn unwind_bodies_drops_senders_in_range() {
+        let command = Command::try_parse_from(["reth", "bodies", "--chain", "dev"]).unwrap();
+        let provider_factory =
+            create_test_provider_factory_with_chain_spec(command.env.chain.clone());
+        init_genesis(provider_factory.clone()).unwrap();
+
+        // One transaction in each of the blocks 1 to 3, with its sender already recovered
+        let provider_rw = provider_factory.provider_rw().unwrap();
+        let static_file_provider = provider_factory.static_file_provider();
+        {
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Transactions).unwrap();
+            for number in 1..=3 {
+                let tx_num = number - 1;
+                writer.increment_block(number).unwrap();
+                writer.append_transaction(tx_num, &TransactionSignedNoHash::default()).unwrap();
+
+                let tx = provider_rw.tx_ref();
+                tx.put::<tables::BlockBodyIndices>(
+                    number,
+                    StoredBlockBodyIndices { first_tx_num: tx_num, tx_count: 1 },
+                )
+                .unwrap();
+                tx.put::<tables::TransactionBlocks>(tx_num, number).unwrap();
+                tx.put::<tables::TransactionSenders>(tx_num, Address::with_last_byte(number as u8))
+                    .unwrap();
+            }
+        }
+        for stage in [StageId::Bodies, StageId::SenderRecovery] {
+            provider_rw
+                .tx_ref()
+                .put::<tables::StageCheckpoints>(stage.to_string(), StageCheckpoint::new(3))
+                .unwrap();
+        }
+        UnifiedStorageWriter::commit(provider_rw, static_file_provider).unwrap();
+
+        unwind_stages_with_pipeline(
+            provider_factory.clone(),
+            &Config::default(),
+            &[StageEnum::Bodies, StageEnum::Senders],
+            1,
+        )
+        .unwrap();
+
+        let provider = provider_factory.provider().unwrap();
+        let tx = provider.tx_ref();
+        assert_eq!(
+            tx.cursor_read::<tables::TransactionSenders>()
+                .unwrap()
+                .walk(None)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![(0, Address::with_last_byte(1))]
+        );
+        assert_eq!(tx.entries::<tables::BlockBodyIndices>().unwrap(), 2);
+        assert_eq!(
+            provider.get_stage_checkpoint(StageId::SenderRecovery).unwrap().map(|c| c.block_number),
+            Some(1)
+        );
+        assert_eq!(
+            provider.get_stage_checkpoint(StageId::Bodies).unwrap().map(|c| c.block_number),
+            Some(1)
+        );
     }
 }
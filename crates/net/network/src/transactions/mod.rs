@@ -1062,6 +1062,11 @@ where
                     let added = new_txs.len();
                     let res = pool.add_external_transactions(new_txs).await;
 
+                    // only transactions that entered the pool are attributed to the peer
+                    for hash in res.iter().flatten() {
+                        pool.on_received_from_peer(*hash, peer_id);
+                    }
+
                     // update metrics
                     metric_pending_pool_imports.decrement(added as f64);
                     // update self-monitoring info
@@ -2061,6 +2066,10 @@ mod tests {
 
         assert!(!pool.is_empty());
         assert!(pool.get(&signed_tx.hash).is_some());
+        assert_eq!(
+            pool.transactions_received_from_peers().get(&signed_tx.hash),
+            Some(handle1.peer_id())
+        );
         handle.terminate().await;
     }
 
@@ -18,6 +18,9 @@ pub use static_file::{
     Transactions as StaticFileTransactions,
 };
 use std::{fmt::Debug, ops::RangeInclusive};
+
+/// The source tag of prune checkpoints saved by the pruner segments.
+pub(crate) const PRUNER_CHECKPOINT_SOURCE: &str = "pruner";
 use tracing::error;
 pub use user::{
     AccountHistory, Receipts as UserReceipts, ReceiptsByLogs, SenderRecovery, StorageHistory,
@@ -54,7 +57,7 @@ pub trait Segment<DB: Database>: Debug + Send + Sync {
         provider: &DatabaseProviderRW<DB>,
         checkpoint: PruneCheckpoint,
     ) -> ProviderResult<()> {
-        provider.save_prune_checkpoint(self.segment(), checkpoint)
+        provider.save_prune_checkpoint_tagged(self.segment(), checkpoint, PRUNER_CHECKPOINT_SOURCE)
     }
 }
 
@@ -64,13 +64,12 @@ pub(crate) fn save_checkpoint<DB: Database>(
     provider: &DatabaseProviderRW<DB>,
     checkpoint: PruneCheckpoint,
 ) -> ProviderResult<()> {
-    provider.save_prune_checkpoint(PruneSegment::Receipts, checkpoint)?;
-
     // `PruneSegment::Receipts` overrides `PruneSegment::ContractLogs`, so we can preemptively
     // limit their pruning start point.
-    provider.save_prune_checkpoint(PruneSegment::ContractLogs, checkpoint)?;
-
-    Ok(())
+    provider.save_prune_checkpoints(
+        &[(PruneSegment::Receipts, checkpoint), (PruneSegment::ContractLogs, checkpoint)],
+        super::PRUNER_CHECKPOINT_SOURCE,
+    )
 }
 
 #[cfg(test)]
@@ -1,5 +1,5 @@
 use crate::{
-    segments::{PruneInput, Segment},
+    segments::{PruneInput, Segment, PRUNER_CHECKPOINT_SOURCE},
     PrunerError,
 };
 use reth_db::tables;
@@ -202,13 +202,14 @@ impl<DB: Database> Segment<DB> for ReceiptsByLogs {
             .lowest_block_with_distance(input.to_block, initial_last_pruned_block)?
             .unwrap_or(to_block);
 
-        provider.save_prune_checkpoint(
+        provider.save_prune_checkpoint_tagged(
             PruneSegment::ContractLogs,
             PruneCheckpoint {
                 block_number: Some(prune_mode_block.min(last_pruned_block.unwrap_or(u64::MAX))),
                 tx_number: last_pruned_transaction,
                 prune_mode: PruneMode::Before(prune_mode_block),
             },
+            PRUNER_CHECKPOINT_SOURCE,
         )?;
 
         let progress = PruneProgress::new(done, &limiter);
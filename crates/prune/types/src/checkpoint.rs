@@ -1,4 +1,4 @@
-use crate::PruneMode;
+use crate::{PruneMode, PruneSegment};
 use alloy_primitives::{BlockNumber, TxNumber};
 use reth_codecs::{add_arbitrary_tests, Compact};
 use serde::{Deserialize, Serialize};
@@ -15,3 +15,19 @@ pub struct PruneCheckpoint {
     /// Prune mode.
     pub prune_mode: PruneMode,
 }
+
+/// The pruning state of a segment relative to a chain tip.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneSegmentState {
+    /// The prune segment.
+    pub segment: PruneSegment,
+    /// Highest pruned block number, if the segment has a checkpoint with a block number.
+    pub block_number: Option<BlockNumber>,
+    /// How many blocks the checkpoint trails the tip by, if it has a block number.
+    pub lag: Option<u64>,
+    /// Prune mode of the checkpoint, if the segment has one.
+    pub prune_mode: Option<PruneMode>,
+    /// Unix timestamp in seconds of when the checkpoint was last saved, if the segment has one.
+    pub last_updated: Option<u64>,
+}
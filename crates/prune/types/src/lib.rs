@@ -15,9 +15,9 @@ mod pruner;
 mod segment;
 mod target;
 
-pub use checkpoint::PruneCheckpoint;
+pub use checkpoint::{PruneCheckpoint, PruneSegmentState};
 pub use limiter::PruneLimiter;
-pub use mode::PruneMode;
+pub use mode::{PruneMode, PruneModeKind};
 pub use pruner::{
     PruneInterruptReason, PruneProgress, PrunerOutput, SegmentOutput, SegmentOutputCheckpoint,
 };
@@ -67,6 +67,28 @@ impl PruneMode {
     pub const fn is_full(&self) -> bool {
         matches!(self, Self::Full)
     }
+
+    /// Returns the variant of the prune mode without its parameter.
+    pub const fn kind(&self) -> PruneModeKind {
+        match self {
+            Self::Full => PruneModeKind::Full,
+            Self::Distance(_) => PruneModeKind::Distance,
+            Self::Before(_) => PruneModeKind::Before,
+        }
+    }
+}
+
+/// The variant of a [`PruneMode`] without its parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PruneModeKind {
+    /// [`PruneMode::Full`].
+    Full,
+    /// [`PruneMode::Distance`] with any distance.
+    Distance,
+    /// [`PruneMode::Before`] with any block number.
+    Before,
+    /// No prune mode is set.
+    Unset,
 }
 
 #[cfg(test)]
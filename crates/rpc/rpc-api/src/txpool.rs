@@ -1,6 +1,15 @@
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use reth_primitives::Address;
-use reth_rpc_types::txpool::{TxpoolContent, TxpoolContentFrom, TxpoolInspect, TxpoolStatus};
+use reth_primitives::{Address, TxHash, B256, U256};
+use reth_rpc_types::txpool::{
+    TxpoolBasefeeDropUnlock, TxpoolBlobGasRatio, TxpoolCascadeRisk, TxpoolChurnStats,
+    TxpoolContent, TxpoolContentDigest, TxpoolContentFrom, TxpoolContentPage, TxpoolContentRanked,
+    TxpoolDependencyGraph, TxpoolFeeCluster, TxpoolFirstEligibleBlock, TxpoolGapBlockedSender,
+    TxpoolGasAnomaly, TxpoolHashBloom, TxpoolInspect, TxpoolInspectBlobs, TxpoolMemoryEstimate,
+    TxpoolMinFeeSimulation, TxpoolMinedCollision, TxpoolNonceGaps, TxpoolOversizedBlobTx,
+    TxpoolPeerContributions, TxpoolPriorityFeeDistribution, TxpoolRankedTransaction, TxpoolStatus,
+    TxpoolSubscriptionFilter, TxpoolTransactionByHash, TxpoolTxStatus, TxpoolWaitEstimate,
+};
+use std::collections::BTreeMap;
 
 /// Txpool rpc interface.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "txpool"))]
@@ -16,6 +25,10 @@ pub trait TxPoolApi {
     /// Returns a summary of all the transactions currently pending for inclusion in the next
     /// block(s), as well as the ones that are being scheduled for future execution only.
     ///
+    /// The reported gas price of EIP-1559 transactions is the effective gas price at the pending
+    /// base fee, `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)`. Legacy transactions
+    /// report their gas price.
+    ///
     /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_inspect) for more details
     #[method(name = "inspect")]
     async fn txpool_inspect(&self) -> RpcResult<TxpoolInspect>;
@@ -33,4 +46,326 @@ pub trait TxPoolApi {
     /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_content) for more details
     #[method(name = "content")]
     async fn txpool_content(&self) -> RpcResult<TxpoolContent>;
+
+    /// Simulates applying all pooled transactions of the given sender in nonce order against the
+    /// sender's on-chain balance and returns the first nonce at which the running balance would go
+    /// negative, together with all transactions from that nonce onwards.
+    #[method(name = "cascadeRisk")]
+    async fn txpool_cascade_risk(&self, address: Address) -> RpcResult<TxpoolCascadeRisk>;
+
+    /// Returns how many of the currently pooled transactions were first received from each peer.
+    ///
+    /// Locally submitted transactions are counted separately. Only transactions that are still in
+    /// the pool are taken into account.
+    #[method(name = "contributionsByPeer")]
+    async fn txpool_contributions_by_peer(&self) -> RpcResult<TxpoolPeerContributions>;
+
+    /// Returns a bloom filter of the given size in bits over the hashes of all pooled
+    /// transactions.
+    ///
+    /// The false-positive rate depends on the ratio of bits to pooled transactions; see
+    /// [`TxpoolHashBloom`] for the exact construction.
+    #[method(name = "hashBloom")]
+    async fn txpool_hash_bloom(&self, bits: usize) -> RpcResult<TxpoolHashBloom>;
+
+    /// Returns the pooled transactions that share a sender and nonce with a transaction included
+    /// in the given block, but have a different hash.
+    ///
+    /// These transactions lost a replacement race and can never be included.
+    #[method(name = "minedCollisions")]
+    async fn txpool_mined_collisions(
+        &self,
+        block_hash: B256,
+    ) -> RpcResult<Vec<TxpoolMinedCollision>>;
+
+    /// Returns the gas limit weighted average of the effective gas price of all pending
+    /// transactions at the current pending base fee.
+    ///
+    /// Returns zero if there are no pending transactions.
+    #[method(name = "gasWeightedAvgFee")]
+    async fn txpool_gas_weighted_avg_fee(&self) -> RpcResult<U256>;
+
+    /// Returns an estimate of the memory consumed by the pooled transactions, broken down into
+    /// pending transactions, queued transactions and the blob store.
+    #[method(name = "memoryEstimate")]
+    async fn txpool_memory_estimate(&self) -> RpcResult<TxpoolMemoryEstimate>;
+
+    /// Returns how many pending and queued transactions have a max fee per gas below the given
+    /// minimum fee and would be evicted if it was enforced.
+    ///
+    /// This does not modify the pool.
+    #[method(name = "simulateMinFee")]
+    async fn txpool_simulate_min_fee(&self, new_min_fee: U256)
+        -> RpcResult<TxpoolMinFeeSimulation>;
+
+    /// Returns a page of the `txpool_content` response.
+    ///
+    /// Pending and queued transactions are ordered together by sender and then nonce, and `limit`
+    /// transactions starting at `offset` are returned. An `offset` past the end yields an empty
+    /// page.
+    ///
+    /// A page holds at most 1024 transactions.
+    #[method(name = "contentPaginated")]
+    async fn txpool_content_paginated(
+        &self,
+        offset: u64,
+        limit: u64,
+    ) -> RpcResult<TxpoolContentPage>;
+
+    /// Returns the `txpool_content` response with only the transactions whose max fee per gas is
+    /// at least `min_gas_price`.
+    ///
+    /// Legacy transactions are compared by their gas price.
+    #[method(name = "contentFiltered")]
+    async fn txpool_content_filtered(&self, min_gas_price: U256) -> RpcResult<TxpoolContent>;
+
+    /// Returns the hashes of the pending transactions in the order they would be included in a
+    /// block if all transactions of the given senders were excluded.
+    ///
+    /// This does not change the pool configuration.
+    #[method(name = "orderedExcluding")]
+    async fn txpool_ordered_excluding(&self, blocked: Vec<Address>) -> RpcResult<Vec<TxHash>>;
+
+    /// Retrieves the transactions contained within the txpool for each of the given addresses,
+    /// grouped by nonce.
+    ///
+    /// Every requested address is present in the response, with empty maps if it has no
+    /// transactions in the pool.
+    ///
+    /// At most 1024 addresses can be requested at once.
+    #[method(name = "contentFromMany")]
+    async fn txpool_content_from_many(
+        &self,
+        addresses: Vec<Address>,
+    ) -> RpcResult<BTreeMap<Address, TxpoolContentFrom>>;
+
+    /// Creates a subscription that sends every transaction that is added to the pending sub-pool.
+    ///
+    /// The optional filter restricts the subscription to a single sender or a minimum tip.
+    #[subscription(
+        name = "subscribe" => "subscription",
+        unsubscribe = "unsubscribe",
+        item = reth_rpc_types::Transaction
+    )]
+    async fn txpool_subscribe(
+        &self,
+        filter: Option<TxpoolSubscriptionFilter>,
+    ) -> jsonrpsee::core::SubscriptionResult;
+
+    /// Returns the senders whose queued transactions are blocked by exactly one missing nonce,
+    /// together with that nonce and the number of transactions that filling it would unblock.
+    #[method(name = "gapBlockedSenders")]
+    async fn txpool_gap_blocked_senders(&self) -> RpcResult<Vec<TxpoolGapBlockedSender>>;
+
+    /// Returns a summary of the blob details of all EIP-4844 transactions in the pool, in the
+    /// same layout as `txpool_inspect`.
+    #[method(name = "inspectBlobs")]
+    async fn txpool_inspect_blobs(&self) -> RpcResult<TxpoolInspectBlobs>;
+
+    /// Returns the number of pending and queued transactions, like `txpool_status`, but reads the
+    /// pool's size counters instead of collecting the transactions.
+    #[method(name = "count")]
+    async fn txpool_count(&self) -> RpcResult<TxpoolStatus>;
+
+    /// Returns a digest over the hashes of all pooled transactions that is independent of their
+    /// order, so two nodes with the same pool content return the same digest.
+    #[method(name = "contentDigest")]
+    async fn txpool_content_digest(&self) -> RpcResult<TxpoolContentDigest>;
+
+    /// Returns the number of pending and queued transactions of the given sender, in the same
+    /// format as `txpool_status`.
+    #[method(name = "statusBySender")]
+    async fn txpool_status_by_sender(&self, from: Address) -> RpcResult<TxpoolStatus>;
+
+    /// Groups the pending transactions by their exact max fee per gas and returns the groups with
+    /// at least `min_cluster` members, largest first.
+    ///
+    /// Large groups of transactions with an identical fee are a common spam signature.
+    #[method(name = "duplicateFeeClusters")]
+    async fn txpool_duplicate_fee_clusters(
+        &self,
+        min_cluster: usize,
+    ) -> RpcResult<Vec<TxpoolFeeCluster>>;
+
+    /// Returns, for every sender with queued transactions stuck behind a missing nonce, the next
+    /// on-chain nonce and the nonces of the stuck transactions.
+    ///
+    /// Senders without nonce gaps are omitted.
+    #[method(name = "nonceGaps")]
+    async fn txpool_nonce_gaps(&self) -> RpcResult<BTreeMap<Address, TxpoolNonceGaps>>;
+
+    /// Returns, for every pooled transaction ordered by sender and nonce, the first block it was
+    /// eligible for inclusion in.
+    ///
+    /// Transactions that have not been pending yet report `null`. See
+    /// [`TxpoolFirstEligibleBlock`] for how the block is approximated.
+    #[method(name = "firstEligibleBlock")]
+    async fn txpool_first_eligible_block(&self) -> RpcResult<Vec<TxpoolFirstEligibleBlock>>;
+
+    /// Returns the `txpool_content` response with the pending transactions as a list ordered by
+    /// descending effective tip at the pending base fee, so the transactions most likely to be
+    /// included come first.
+    ///
+    /// Queued transactions are not eligible for inclusion and are grouped by sender and nonce.
+    #[method(name = "contentRanked")]
+    async fn txpool_content_ranked(&self) -> RpcResult<TxpoolContentRanked>;
+
+    /// Returns the gas price the pooled transaction with the given hash would pay if it was
+    /// included at the pending base fee, `min(max_fee_per_gas, base_fee +
+    /// max_priority_fee_per_gas)`, or the gas price for legacy transactions.
+    ///
+    /// Returns an error if the transaction is not in the pool.
+    #[method(name = "effectiveGasPrice")]
+    async fn txpool_effective_gas_price(&self, hash: B256) -> RpcResult<U256>;
+
+    /// Returns the `txpool_content` response with only the transactions that were submitted
+    /// locally.
+    ///
+    /// Nodes that only received transactions from the network return empty maps.
+    #[method(name = "locals")]
+    async fn txpool_locals(&self) -> RpcResult<TxpoolContent>;
+
+    /// Returns whether the transaction with the given hash is pending, queued or not in the pool.
+    ///
+    /// For queued transactions the response lists why the transaction is not pending, as
+    /// classified by the pool.
+    #[method(name = "txStatus")]
+    async fn txpool_tx_status(&self, hash: B256) -> RpcResult<TxpoolTxStatus>;
+
+    /// Returns how many transactions were newly added, added as fee-bump replacements and removed
+    /// within the last `window_secs` seconds.
+    ///
+    /// The window may be at most one hour.
+    #[method(name = "churnStats")]
+    async fn txpool_churn_stats(&self, window_secs: u64) -> RpcResult<TxpoolChurnStats>;
+
+    /// Returns the pooled transactions with a gas limit above `min_gas` but (almost) no calldata,
+    /// ordered by descending gas limit.
+    ///
+    /// Plain transfers only need 21000 gas, so a high gas limit without calldata is a common
+    /// signature of spam.
+    #[method(name = "gasAnomalies")]
+    async fn txpool_gas_anomalies(&self, min_gas: u64) -> RpcResult<Vec<TxpoolGasAnomaly>>;
+
+    /// Returns the `txpool_content` response with only the transactions sent by an account to
+    /// itself, which are commonly used to cancel a pending transaction.
+    ///
+    /// Contract creations are never included.
+    #[method(name = "selfSends")]
+    async fn txpool_self_sends(&self) -> RpcResult<TxpoolContent>;
+
+    /// Estimates how long a transaction paying the given effective gas price would wait for
+    /// inclusion, if blocks are produced at `blocks_per_sec`.
+    ///
+    /// The estimate assumes that every block is filled up to the current block gas limit with the
+    /// pending transactions of the highest effective gas price at the pending base fee, and that
+    /// neither new transactions arrive nor the base fee changes. Returns an error if
+    /// `blocks_per_sec` is not positive.
+    #[method(name = "estimateWait")]
+    async fn txpool_estimate_wait(
+        &self,
+        fee: U256,
+        blocks_per_sec: f64,
+    ) -> RpcResult<TxpoolWaitEstimate>;
+
+    /// Returns the pooled transaction and its sub-pool for each of the given hashes, in the same
+    /// order, or `null` for hashes that are not in the pool.
+    ///
+    /// At most 1024 hashes can be requested at once.
+    #[method(name = "transactionsByHash")]
+    async fn txpool_transactions_by_hash(
+        &self,
+        hashes: Vec<B256>,
+    ) -> RpcResult<Vec<Option<TxpoolTransactionByHash>>>;
+
+    /// Returns the blob gas and execution gas demanded by pending transactions and their ratio.
+    ///
+    /// Blob gas is derived from the blob counts of the transactions and execution gas from their
+    /// gas limits. Without blob transactions the blob component is zero.
+    #[method(name = "blobGasRatio")]
+    async fn txpool_blob_gas_ratio(&self) -> RpcResult<TxpoolBlobGasRatio>;
+
+    /// Returns a random sample of up to `n` pending transactions, drawn without replacement.
+    ///
+    /// The sample is deterministic for a given `seed` and pool content. `n` is capped at 1024.
+    #[method(name = "sample")]
+    async fn txpool_sample(
+        &self,
+        n: usize,
+        seed: u64,
+    ) -> RpcResult<Vec<reth_rpc_types::Transaction>>;
+
+    /// Returns the queued transactions whose max fee per gas is below the current base fee but
+    /// would meet it if the base fee dropped by `drop_pct` percent.
+    ///
+    /// `drop_pct` must be within `1..=100`.
+    #[method(name = "unlockOnBasefeeDrop")]
+    async fn txpool_unlock_on_basefee_drop(
+        &self,
+        drop_pct: u8,
+    ) -> RpcResult<TxpoolBasefeeDropUnlock>;
+
+    /// Returns the number of pending transactions per max priority fee per gas range.
+    ///
+    /// The strictly increasing `bucket_bounds` split the priority fees into the ranges below the
+    /// first bound, between each pair of bounds and from the last bound upwards. Transactions
+    /// without a separate priority fee are counted separately.
+    #[method(name = "priorityFeeDistribution")]
+    async fn txpool_priority_fee_distribution(
+        &self,
+        bucket_bounds: Vec<U256>,
+    ) -> RpcResult<TxpoolPriorityFeeDistribution>;
+
+    /// Returns the pooled transaction at the given sender and nonce, or `None` if there is none.
+    ///
+    /// Reflects the latest replacement of the transaction at that nonce.
+    #[method(name = "slotOccupant")]
+    async fn txpool_slot_occupant(
+        &self,
+        sender: Address,
+        nonce: u64,
+    ) -> RpcResult<Option<reth_rpc_types::Transaction>>;
+
+    /// Returns the chain of the given sender's pooled transactions in nonce order, marking the
+    /// transaction that is includable at the on-chain nonce and which transactions wait for an
+    /// earlier one.
+    #[method(name = "dependencyGraph")]
+    async fn txpool_dependency_graph(&self, sender: Address) -> RpcResult<TxpoolDependencyGraph>;
+
+    /// Returns, for every sender whose pooled transactions have a lower max fee per gas than the
+    /// transaction with the preceding nonce, the nonces at which the fee decreases.
+    ///
+    /// A later nonce paying less can stall the sender's queue. Senders with non-decreasing fees
+    /// are omitted.
+    #[method(name = "nonmonotonicFeeQueues")]
+    async fn txpool_nonmonotonic_fee_queues(&self) -> RpcResult<BTreeMap<Address, Vec<u64>>>;
+
+    /// Returns the number of pooled transactions that arrived in each `bucket_secs` interval of
+    /// the last `window_secs` seconds, oldest bucket first.
+    ///
+    /// Only transactions that are still in the pool are counted. `bucket_secs` must be non-zero
+    /// and divide `window_secs`, and the window may span at most 3600 buckets.
+    #[method(name = "arrivalTimeline")]
+    async fn txpool_arrival_timeline(
+        &self,
+        window_secs: u64,
+        bucket_secs: u64,
+    ) -> RpcResult<Vec<u64>>;
+
+    /// Returns the pooled blob transactions that carry more blobs than a transaction may have.
+    ///
+    /// Such transactions can never be included, so any entry points at a validation bug. Without
+    /// blob transactions the response is empty.
+    #[method(name = "oversizedBlobTxs")]
+    async fn txpool_oversized_blob_txs(&self) -> RpcResult<Vec<TxpoolOversizedBlobTx>>;
+
+    /// Returns up to `limit` pending transactions ordered by descending effective tip per gas at
+    /// the pending base fee, the order a block builder picks them in.
+    ///
+    /// Transactions with the same tip are ordered by hash. `limit` is capped at 1024.
+    #[method(name = "byProfitability")]
+    async fn txpool_by_profitability(
+        &self,
+        limit: usize,
+    ) -> RpcResult<Vec<TxpoolRankedTransaction>>;
 }
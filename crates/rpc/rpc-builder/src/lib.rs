@@ -165,7 +165,8 @@ use reth_rpc::{
 use reth_rpc_api::servers::*;
 use reth_rpc_eth_api::{
     helpers::{
-        Call, EthApiSpec, EthTransactions, LoadPendingBlock, TraceExt, UpdateRawTxForwarder,
+        Call, EthApiSpec, EthTransactions, LoadPendingBlock, SpawnBlocking, TraceExt,
+        UpdateRawTxForwarder,
     },
     EthApiServer, FullEthApiServer, RawTransactionForwarder,
 };
@@ -1080,9 +1081,14 @@ where
                         .into_rpc()
                         .into(),
                         RethRpcModule::Web3 => Web3Api::new(self.network.clone()).into_rpc().into(),
-                        RethRpcModule::Txpool => {
-                            TxPoolApi::new(self.pool.clone()).into_rpc().into()
-                        }
+                        RethRpcModule::Txpool => TxPoolApi::with_spawner(
+                            self.provider.clone(),
+                            self.pool.clone(),
+                            eth_api.tracing_task_pool().clone(),
+                            Box::new(self.executor.clone()),
+                        )
+                        .into_rpc()
+                        .into(),
                         RethRpcModule::Rpc => RPCApi::new(
                             namespaces
                                 .iter()
@@ -43,8 +43,8 @@ pub use alloy_rpc_types_mev as mev;
 #[cfg(feature = "jsonrpsee-types")]
 pub use alloy_rpc_types_beacon as beacon;
 
-// re-export txpool
-pub use alloy_rpc_types_txpool as txpool;
+// txpool types, including reth specific extensions
+pub mod txpool;
 
 // Ethereum specific rpc types related to typed transaction requests and the engine API.
 #[cfg(feature = "jsonrpsee-types")]
@@ -0,0 +1,510 @@
+//! RPC types for the `txpool` namespace.
+//!
+//! Re-exports the standard types from alloy and adds reth specific response types.
+
+use crate::{PeerId, Transaction};
+use alloy_primitives::{Address, Bytes, TxHash, B256, U256};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub use alloy_rpc_types_txpool::*;
+
+/// Response type for `txpool_cascadeRisk`.
+///
+/// Describes which of a sender's pooled transactions would become underfunded if all of the
+/// sender's transactions were applied in nonce order against the current on-chain balance.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolCascadeRisk {
+    /// The sender that was analyzed.
+    pub sender: Address,
+    /// The on-chain balance of the sender at the latest block.
+    pub balance: U256,
+    /// The first nonce whose cost exceeds the remaining balance, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub first_underfunded_nonce: Option<u64>,
+    /// Hashes of the transaction at `first_underfunded_nonce` and all subsequent transactions of
+    /// the sender, in nonce order.
+    pub at_risk: Vec<TxHash>,
+}
+
+/// Response type for `txpool_contributionsByPeer`.
+///
+/// Counts the currently pooled transactions by where they were first received from. Transactions
+/// that have already left the pool are not accounted for.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolPeerContributions {
+    /// Number of transactions that were submitted locally.
+    pub local: u64,
+    /// Number of external transactions for which the source peer is unknown, for example because
+    /// they were reinserted after a reorg.
+    pub unknown: u64,
+    /// Number of transactions first received from each peer.
+    pub peers: BTreeMap<PeerId, u64>,
+}
+
+/// Response type for `txpool_hashBloom`.
+///
+/// A bloom filter over the hashes of all pooled transactions that peers can use to cheaply check
+/// which transactions this node already has.
+///
+/// Bit `i` of the filter is stored in byte `i / 8` at bit position `i % 8` (least significant bit
+/// first). For a transaction hash `h`, let `h1` and `h2` be the big-endian `u64` values of
+/// `h[0..8]` and `h[8..16]`. The hash is inserted by setting the bits
+/// `(h1 + i * h2) mod bits` for every `i` in `0..hash_count`, using wrapping `u64` arithmetic.
+///
+/// A bloom filter has no false negatives but may report hashes that are not in the pool. With `n`
+/// items, `m` bits and `k` hash functions the false-positive rate is approximately
+/// `(1 - e^(-k * n / m))^k`, so roughly 10 bits per pooled transaction yield a rate of about 1%.
+/// Requesting fewer bits than transactions in the pool makes the filter mostly useless.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolHashBloom {
+    /// The filter bits.
+    pub bloom: Bytes,
+    /// The size of the filter in bits.
+    #[serde(with = "alloy_serde::quantity")]
+    pub bits: u64,
+    /// The number of hash functions used per item.
+    #[serde(with = "alloy_serde::quantity")]
+    pub hash_count: u64,
+    /// The number of transaction hashes inserted into the filter.
+    #[serde(with = "alloy_serde::quantity")]
+    pub items: u64,
+}
+
+/// An entry of the `txpool_minedCollisions` response.
+///
+/// A pooled transaction that uses the same sender and nonce as a transaction included in a block,
+/// but has a different hash and therefore can no longer be included.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolMinedCollision {
+    /// The sender of both transactions.
+    pub sender: Address,
+    /// The nonce shared by both transactions.
+    #[serde(with = "alloy_serde::quantity")]
+    pub nonce: u64,
+    /// The hash of the pooled transaction that lost.
+    pub pool_hash: TxHash,
+    /// The hash of the transaction that was included in the block.
+    pub mined_hash: TxHash,
+}
+
+/// Response type for `txpool_memoryEstimate`.
+///
+/// All values are estimates in bytes, based on the transaction sizes the pool tracks for its size
+/// limits plus a fixed per-transaction overhead for the pool's bookkeeping.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolMemoryEstimate {
+    /// Bytes consumed by pending transactions.
+    #[serde(with = "alloy_serde::quantity")]
+    pub pending: u64,
+    /// Bytes consumed by queued transactions, including the basefee and blob sub-pools.
+    #[serde(with = "alloy_serde::quantity")]
+    pub queued: u64,
+    /// Bytes consumed by blob sidecars in the blob store, if the store can report it.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub blob_store: Option<u64>,
+    /// Sum of all components.
+    #[serde(with = "alloy_serde::quantity")]
+    pub total: u64,
+}
+
+/// Response type for `txpool_simulateMinFee`.
+///
+/// Describes which pooled transactions have a max fee per gas below a proposed minimum fee and
+/// would therefore be dropped if that minimum was enforced.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolMinFeeSimulation {
+    /// Number of pending transactions below the proposed minimum fee.
+    #[serde(with = "alloy_serde::quantity")]
+    pub pending: u64,
+    /// Number of queued transactions below the proposed minimum fee.
+    #[serde(with = "alloy_serde::quantity")]
+    pub queued: u64,
+    /// A bounded sample of the hashes of affected transactions, pending ones first.
+    pub sample: Vec<TxHash>,
+}
+
+/// Response type for `txpool_contentPaginated`.
+///
+/// A page of the `txpool_content` response, with transactions ordered by sender and nonce.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolContentPage {
+    /// The pending and queued transactions of this page.
+    #[serde(flatten)]
+    pub content: TxpoolContent,
+    /// Whether there are more transactions after this page.
+    pub has_more: bool,
+}
+
+/// Filter for `txpool_subscribe`.
+///
+/// Only transactions that match all of the set criteria are sent to the subscriber.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolSubscriptionFilter {
+    /// Only include transactions of this sender.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<Address>,
+    /// Only include transactions with at least this priority fee, or gas price for legacy
+    /// transactions.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub min_tip: Option<u128>,
+}
+
+impl TxpoolSubscriptionFilter {
+    /// Returns whether a transaction with the given sender and priority fee matches the filter.
+    pub fn matches(&self, sender: Address, tip: u128) -> bool {
+        self.sender.map_or(true, |expected| expected == sender) &&
+            self.min_tip.map_or(true, |min_tip| tip >= min_tip)
+    }
+}
+
+/// An entry of the `txpool_gapBlockedSenders` response.
+///
+/// A sender whose queued transactions are all blocked by a single missing nonce.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolGapBlockedSender {
+    /// The sender of the blocked transactions.
+    pub sender: Address,
+    /// The only nonce missing between the on-chain nonce and the highest pooled nonce.
+    #[serde(with = "alloy_serde::quantity")]
+    pub missing_nonce: u64,
+    /// The number of pooled transactions after the missing nonce that it would unblock.
+    #[serde(with = "alloy_serde::quantity")]
+    pub unblocks: u64,
+}
+
+/// Blob details of a single transaction in the `txpool_inspectBlobs` response.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolBlobSummary {
+    /// The max fee per blob gas the transaction is willing to pay.
+    #[serde(with = "alloy_serde::quantity")]
+    pub max_fee_per_blob_gas: u128,
+    /// The number of blob versioned hashes of the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub blob_count: u64,
+}
+
+/// Response type for `txpool_inspectBlobs`.
+///
+/// The EIP-4844 counterpart to [`TxpoolInspect`], containing only blob transactions grouped by
+/// sender and nonce.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxpoolInspectBlobs {
+    /// Pending blob transactions.
+    pub pending: BTreeMap<Address, BTreeMap<String, TxpoolBlobSummary>>,
+    /// Queued blob transactions.
+    pub queued: BTreeMap<Address, BTreeMap<String, TxpoolBlobSummary>>,
+}
+
+/// Response type for `txpool_contentDigest`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolContentDigest {
+    /// Keccak-256 hash over the concatenation of all pooled transaction hashes in ascending order.
+    pub digest: B256,
+    /// The number of pooled transactions.
+    #[serde(with = "alloy_serde::quantity")]
+    pub count: u64,
+}
+
+/// An entry of the `txpool_duplicateFeeClusters` response.
+///
+/// A group of pending transactions that all use the exact same max fee per gas.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolFeeCluster {
+    /// The max fee per gas shared by all transactions of the cluster.
+    #[serde(with = "alloy_serde::quantity")]
+    pub max_fee_per_gas: u128,
+    /// The hashes of the transactions in the cluster.
+    pub hashes: Vec<TxHash>,
+}
+
+/// An entry of the `txpool_nonceGaps` response.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolNonceGaps {
+    /// The next nonce of the sender according to the latest state.
+    #[serde(with = "alloy_serde::quantity")]
+    pub next_nonce: u64,
+    /// The queued nonces that can't be executed because a nonce before them is missing.
+    pub gapped_nonces: Vec<u64>,
+}
+
+/// An entry of the `txpool_firstEligibleBlock` response.
+///
+/// The pool does not re-evaluate transactions on every block, so the first eligible block is
+/// approximated: it is the block after the last block the pool had seen when the transaction first
+/// entered the pending sub-pool, either on arrival or when it was promoted after a block or an
+/// account update. Transactions that were demoted afterwards keep their first eligible block.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolFirstEligibleBlock {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// The sender of the transaction.
+    pub sender: Address,
+    /// The nonce of the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub nonce: u64,
+    /// The first block the transaction was eligible for inclusion in, or `None` if it has not been
+    /// pending yet.
+    #[serde(default, with = "alloy_serde::quantity::opt")]
+    pub first_eligible_block: Option<u64>,
+}
+
+/// A pending transaction of the `txpool_contentRanked` response.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolRankedTransaction {
+    /// The sender of the transaction.
+    pub sender: Address,
+    /// The nonce of the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub nonce: u64,
+    /// The priority fee the transaction pays at the pending base fee.
+    #[serde(with = "alloy_serde::quantity")]
+    pub effective_tip: u128,
+    /// The transaction.
+    pub transaction: Transaction,
+}
+
+/// Response type for `txpool_contentRanked`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TxpoolContentRanked {
+    /// Pending transactions, ordered by descending effective tip.
+    pub pending: Vec<TxpoolRankedTransaction>,
+    /// Queued transactions grouped by sender and nonce, like in `txpool_content`.
+    pub queued: BTreeMap<Address, BTreeMap<String, Transaction>>,
+}
+
+/// Why a transaction in the `txpool_txStatus` response is queued instead of pending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TxpoolQueuedReason {
+    /// A transaction with a lower nonce of the same sender is missing.
+    NonceGap,
+    /// A transaction with a lower nonce of the same sender is not pending.
+    ParkedAncestor,
+    /// The sender can't cover the cost of this transaction and all prior transactions.
+    InsufficientBalance,
+    /// The gas limit of the transaction exceeds the block gas limit.
+    ExceedsBlockGasLimit,
+    /// The max fee per gas is below the base fee of the pending block.
+    InsufficientBaseFee,
+    /// The max fee per blob gas is below the blob fee of the pending block.
+    InsufficientBlobFee,
+}
+
+/// Response type for `txpool_txStatus`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum TxpoolTxStatus {
+    /// The transaction is ready to be included in the next block.
+    Pending,
+    /// The transaction is parked until the given requirements of the pending sub-pool are met.
+    Queued {
+        /// The requirements the transaction does not meet.
+        reasons: Vec<TxpoolQueuedReason>,
+    },
+    /// The transaction is not in the pool.
+    NotFound,
+}
+
+/// Response type for `txpool_churnStats`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolChurnStats {
+    /// Number of transactions added without replacing another transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub new_count: u64,
+    /// Number of transactions that replaced a pooled transaction with the same sender and nonce.
+    #[serde(with = "alloy_serde::quantity")]
+    pub replaced_count: u64,
+    /// Number of transactions that left the pool because they were mined, discarded or removed.
+    #[serde(with = "alloy_serde::quantity")]
+    pub removed_count: u64,
+}
+
+/// An entry of the `txpool_gasAnomalies` response.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolGasAnomaly {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// The sender of the transaction.
+    pub sender: Address,
+    /// The nonce of the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub nonce: u64,
+    /// The gas limit of the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_limit: u64,
+    /// The length of the transaction's calldata in bytes.
+    #[serde(with = "alloy_serde::quantity")]
+    pub input_len: u64,
+}
+
+/// Response type for `txpool_estimateWait`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolWaitEstimate {
+    /// Number of pending transactions that pay a higher effective gas price.
+    #[serde(with = "alloy_serde::quantity")]
+    pub transactions_ahead: u64,
+    /// Total gas limit of the pending transactions that pay a higher effective gas price.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_ahead: u64,
+    /// Estimated number of blocks until a transaction paying the fee is included, including the
+    /// block it is included in.
+    #[serde(with = "alloy_serde::quantity")]
+    pub blocks: u64,
+    /// Estimated time until a transaction paying the fee is included, in seconds.
+    #[serde(with = "alloy_serde::quantity")]
+    pub wait_secs: u64,
+}
+
+/// The sub-pool of a transaction in the `txpool_transactionsByHash` response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TxpoolSubPool {
+    /// The transaction is ready to be included in the next block.
+    Pending,
+    /// The transaction is not yet eligible for inclusion.
+    Queued,
+}
+
+/// An entry of the `txpool_transactionsByHash` response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolTransactionByHash {
+    /// The pooled transaction.
+    pub transaction: Transaction,
+    /// Whether the transaction is pending or queued.
+    pub sub_pool: TxpoolSubPool,
+}
+
+/// Response type for `txpool_blobGasRatio`.
+///
+/// Compares the blob gas demanded by pending transactions with their execution gas demand.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolBlobGasRatio {
+    /// The blob gas of all pending blob transactions, derived from their blob counts.
+    #[serde(with = "alloy_serde::quantity")]
+    pub total_blob_gas: u64,
+    /// The sum of the gas limits of all pending transactions.
+    #[serde(with = "alloy_serde::quantity")]
+    pub total_execution_gas: u64,
+    /// `total_blob_gas / total_execution_gas`, or zero if there is no execution gas demand.
+    pub blob_ratio: f64,
+}
+
+/// Response type for `txpool_unlockOnBasefeeDrop`.
+///
+/// The queued transactions that are parked below the current base fee but would be includable
+/// if the base fee dropped by a given percentage.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolBasefeeDropUnlock {
+    /// The base fee of the pending block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub base_fee: u64,
+    /// The base fee after the hypothetical drop.
+    #[serde(with = "alloy_serde::quantity")]
+    pub hypothetical_base_fee: u64,
+    /// The transactions whose max fee per gas is below the current base fee but at least the
+    /// hypothetical one, ordered by sender and nonce.
+    pub transactions: Vec<Transaction>,
+}
+
+/// A priority fee range and the number of pending transactions within it in the
+/// `txpool_priorityFeeDistribution` response.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolPriorityFeeBucket {
+    /// The inclusive lower bound of the max priority fee per gas.
+    pub min: U256,
+    /// The exclusive upper bound of the max priority fee per gas, `None` for the last bucket.
+    pub max: Option<U256>,
+    /// The number of pending transactions in this bucket.
+    #[serde(with = "alloy_serde::quantity")]
+    pub count: u64,
+}
+
+/// Response type for `txpool_priorityFeeDistribution`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolPriorityFeeDistribution {
+    /// The buckets of pending transactions with a max priority fee per gas, in ascending order.
+    pub buckets: Vec<TxpoolPriorityFeeBucket>,
+    /// The number of pending transactions without a separate priority fee, such as legacy
+    /// transactions.
+    #[serde(with = "alloy_serde::quantity")]
+    pub legacy: u64,
+}
+
+/// A pooled transaction in the `txpool_dependencyGraph` response.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolDependencyNode {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// The nonce of the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub nonce: u64,
+    /// The pooled transaction at the previous nonce that has to be mined first, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<TxHash>,
+    /// Whether a nonce between the on-chain nonce and this transaction is missing from the pool,
+    /// so the transaction can't be included until that nonce arrives.
+    pub gapped: bool,
+}
+
+/// Response type for `txpool_dependencyGraph`.
+///
+/// The chain of a sender's pooled transactions in nonce order. Only the transaction at the
+/// on-chain nonce is includable right away, every later transaction depends on its predecessor.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolDependencyGraph {
+    /// The sender that was analyzed.
+    pub sender: Address,
+    /// The on-chain nonce of the sender at the latest block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub on_chain_nonce: u64,
+    /// The transaction at the on-chain nonce, if it is pooled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_includable: Option<TxHash>,
+    /// The pooled transactions of the sender at or above the on-chain nonce, in nonce order.
+    pub transactions: Vec<TxpoolDependencyNode>,
+}
+
+/// An entry of the `txpool_oversizedBlobTxs` response.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolOversizedBlobTx {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// The sender of the transaction.
+    pub sender: Address,
+    /// The nonce of the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub nonce: u64,
+    /// The number of blobs of the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub blob_count: u64,
+    /// The maximum number of blobs a transaction may carry.
+    #[serde(with = "alloy_serde::quantity")]
+    pub max_blob_count: u64,
+}
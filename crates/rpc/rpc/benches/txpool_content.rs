@@ -0,0 +1,63 @@
+#![allow(missing_docs, unreachable_pub)]
+use criterion::{criterion_group, criterion_main, Criterion};
+use futures::executor::block_on;
+use reth_provider::test_utils::NoopProvider;
+use reth_rpc::TxPoolApi;
+use reth_rpc_api::TxPoolApiServer;
+use reth_rpc_types::txpool::TxpoolContent;
+use reth_tasks::pool::BlockingTaskPool;
+use reth_transaction_pool::{
+    test_utils::{MockTransaction, TestPool, TestPoolBuilder},
+    AllPoolTransactions, PoolConfig, SubPoolLimit, TransactionOrigin, TransactionPool,
+};
+use std::time::Duration;
+
+/// The number of pooled transactions, all pending and from distinct senders.
+const TRANSACTIONS: usize = 20_000;
+
+pub fn txpool_content(c: &mut Criterion) {
+    let pool = populated_pool();
+    // Take a new pool snapshot for every call, like the serial baseline does
+    let api =
+        TxPoolApi::new(NoopProvider::default(), pool.clone(), BlockingTaskPool::build().unwrap())
+            .with_cache_ttl(Duration::ZERO);
+
+    let mut group = c.benchmark_group("txpool_content");
+    group.sample_size(10);
+    group.bench_function("serial", |b| b.iter(|| serial_content(&pool)));
+    group.bench_function("parallel", |b| b.iter(|| block_on(api.txpool_content()).unwrap()));
+    group.finish();
+}
+
+fn populated_pool() -> TestPool {
+    let limit = SubPoolLimit { max_txs: TRANSACTIONS, max_size: usize::MAX };
+    let pool: TestPool = TestPoolBuilder::default()
+        .with_config(PoolConfig { pending_limit: limit, ..Default::default() })
+        .into();
+
+    let transactions = (0..TRANSACTIONS).map(|_| MockTransaction::eip1559()).collect::<Vec<_>>();
+    for result in block_on(pool.add_transactions(TransactionOrigin::External, transactions)) {
+        result.unwrap();
+    }
+    pool
+}
+
+/// Builds the `txpool_content` response on a single thread.
+fn serial_content(pool: &TestPool) -> TxpoolContent {
+    let AllPoolTransactions { pending, queued } = pool.all_transactions();
+
+    let mut content = TxpoolContent::default();
+    for (transactions, content) in [(pending, &mut content.pending), (queued, &mut content.queued)]
+    {
+        for tx in transactions {
+            content.entry(tx.sender()).or_default().insert(
+                tx.nonce().to_string(),
+                reth_rpc_types_compat::transaction::from_recovered(tx.transaction.clone().into()),
+            );
+        }
+    }
+    content
+}
+
+criterion_group!(benches, txpool_content);
+criterion_main!(benches);
@@ -1,64 +1,941 @@
+use crate::eth::pubsub::pipe_from_stream;
 use async_trait::async_trait;
-use jsonrpsee::core::RpcResult as Result;
-use reth_primitives::{Address, TransactionSignedEcRecovered};
+use futures::StreamExt;
+use jsonrpsee::{core::RpcResult as Result, PendingSubscriptionSink};
+use metrics::Histogram;
+use parking_lot::Mutex;
+use rand::{rngs::StdRng, SeedableRng};
+use rayon::prelude::*;
+use reth_metrics::Metrics;
+use reth_primitives::{
+    constants::{
+        eip4844::{DATA_GAS_PER_BLOB, MAX_BLOBS_PER_BLOCK},
+        ETHEREUM_BLOCK_GAS_LIMIT,
+    },
+    keccak256, Address, IntoRecoveredTransaction, TransactionSignedEcRecovered, TxHash, B256, U256,
+};
+use reth_provider::{BlockReader, HeaderProvider, StateProviderFactory, TransactionVariant};
 use reth_rpc_api::TxPoolApiServer;
+use reth_rpc_eth_types::EthApiError;
+use reth_rpc_server_types::result::invalid_params_rpc_err;
 use reth_rpc_types::{
-    txpool::{TxpoolContent, TxpoolContentFrom, TxpoolInspect, TxpoolInspectSummary, TxpoolStatus},
+    txpool::{
+        TxpoolBasefeeDropUnlock, TxpoolBlobGasRatio, TxpoolBlobSummary, TxpoolCascadeRisk,
+        TxpoolChurnStats, TxpoolContent, TxpoolContentDigest, TxpoolContentFrom, TxpoolContentPage,
+        TxpoolContentRanked, TxpoolDependencyGraph, TxpoolDependencyNode, TxpoolFeeCluster,
+        TxpoolFirstEligibleBlock, TxpoolGapBlockedSender, TxpoolGasAnomaly, TxpoolHashBloom,
+        TxpoolInspect, TxpoolInspectBlobs, TxpoolInspectSummary, TxpoolMemoryEstimate,
+        TxpoolMinFeeSimulation, TxpoolMinedCollision, TxpoolNonceGaps, TxpoolOversizedBlobTx,
+        TxpoolPeerContributions, TxpoolPriorityFeeBucket, TxpoolPriorityFeeDistribution,
+        TxpoolQueuedReason, TxpoolRankedTransaction, TxpoolStatus, TxpoolSubPool,
+        TxpoolSubscriptionFilter, TxpoolTransactionByHash, TxpoolTxStatus, TxpoolWaitEstimate,
+    },
     Transaction,
 };
-use reth_transaction_pool::{AllPoolTransactions, PoolTransaction, TransactionPool};
-use std::collections::BTreeMap;
+use reth_tasks::{pool::BlockingTaskPool, TaskSpawner, TokioTaskExecutor};
+use reth_transaction_pool::{
+    pool::MAX_CHURN_WINDOW, AllPoolTransactions, ChurnStats, PoolTransaction, QueuedReason,
+    TransactionPool, ValidPoolTransaction,
+};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tracing::trace;
 
 /// `txpool` API implementation.
 ///
 /// This type provides the functionality for handling `txpool` related requests.
 #[derive(Clone)]
-pub struct TxPoolApi<Pool> {
+pub struct TxPoolApi<Provider, Pool: TransactionPool> {
+    /// The provider that can interact with the chain.
+    provider: Provider,
     /// An interface to interact with the pool
     pool: Pool,
+    /// The most recent snapshot of all pool transactions and when it was taken, shared by
+    /// concurrent requests.
+    all_transactions_cache: Arc<Mutex<Option<AllTransactionsSnapshot<Pool::Transaction>>>>,
+    /// How long a snapshot is served from the cache before it is refreshed.
+    cache_ttl: Duration,
+    /// Result size metrics of the handlers.
+    metrics: TxPoolApiMetrics,
+    /// The pool that CPU heavy responses like `txpool_content` are built on.
+    blocking_task_pool: BlockingTaskPool,
+    /// The type that's used to spawn subscription tasks.
+    subscription_task_spawner: Box<dyn TaskSpawner>,
+}
+
+/// A snapshot of all pool transactions and the time it was taken.
+type AllTransactionsSnapshot<T> = (Instant, Arc<AllPoolTransactions<T>>);
+
+/// Result size metrics of the `txpool` API.
+///
+/// Call latencies are recorded for every RPC method by the RPC server.
+#[derive(Metrics, Clone)]
+#[metrics(scope = "rpc.txpool")]
+struct TxPoolApiMetrics {
+    /// The number of transactions returned by `txpool_content`
+    content_transactions: Histogram,
+    /// The number of transactions returned by `txpool_inspect`
+    inspect_transactions: Histogram,
 }
 
-impl<Pool> TxPoolApi<Pool> {
+impl<Provider, Pool: TransactionPool> TxPoolApi<Provider, Pool> {
     /// Creates a new instance of `TxpoolApi`.
-    pub const fn new(pool: Pool) -> Self {
-        Self { pool }
+    ///
+    /// Subscription tasks are spawned via [`tokio::task::spawn`]
+    pub fn new(provider: Provider, pool: Pool, blocking_task_pool: BlockingTaskPool) -> Self {
+        Self::with_spawner(provider, pool, blocking_task_pool, Box::<TokioTaskExecutor>::default())
+    }
+
+    /// Creates a new instance of `TxpoolApi` that spawns subscription tasks with the given
+    /// spawner.
+    pub fn with_spawner(
+        provider: Provider,
+        pool: Pool,
+        blocking_task_pool: BlockingTaskPool,
+        subscription_task_spawner: Box<dyn TaskSpawner>,
+    ) -> Self {
+        Self {
+            provider,
+            pool,
+            all_transactions_cache: Default::default(),
+            cache_ttl: DEFAULT_TXPOOL_CACHE_TTL,
+            metrics: Default::default(),
+            blocking_task_pool,
+            subscription_task_spawner,
+        }
+    }
+
+    /// Sets how long a snapshot of all pool transactions is shared between requests, 200ms by
+    /// default.
+    ///
+    /// A zero TTL disables the cache.
+    pub const fn with_cache_ttl(mut self, cache_ttl: Duration) -> Self {
+        self.cache_ttl = cache_ttl;
+        self
+    }
+
+    /// Returns all pool transactions, reusing a snapshot that is younger than the cache TTL.
+    ///
+    /// The lock is held while a new snapshot is taken, so concurrent requests wait for and share
+    /// the same snapshot instead of each fetching their own.
+    fn all_transactions(&self) -> Arc<AllPoolTransactions<Pool::Transaction>> {
+        let mut cache = self.all_transactions_cache.lock();
+        if let Some((taken_at, all)) = cache.as_ref() {
+            if taken_at.elapsed() < self.cache_ttl {
+                return all.clone()
+            }
+        }
+
+        let all = Arc::new(self.pool.all_transactions());
+        *cache = Some((Instant::now(), all.clone()));
+        all
     }
 }
 
-impl<Pool> TxPoolApi<Pool>
+impl<Provider, Pool> TxPoolApi<Provider, Pool>
 where
+    Provider: StateProviderFactory + BlockReader + 'static,
     Pool: TransactionPool + 'static,
 {
-    fn content(&self) -> TxpoolContent {
-        #[inline]
-        fn insert<T: PoolTransaction>(
-            tx: &T,
-            content: &mut BTreeMap<Address, BTreeMap<String, Transaction>>,
-        ) {
+    async fn content(&self) -> Result<TxpoolContent> {
+        self.content_filtered(|_| true).await
+    }
+
+    /// Returns the `txpool_content` response with only the transactions matching the filter.
+    ///
+    /// The response is built on the blocking task pool, since converting a large pool is CPU
+    /// heavy.
+    async fn content_filtered(
+        &self,
+        filter: impl Fn(&ValidPoolTransaction<Pool::Transaction>) -> bool + Send + Sync + 'static,
+    ) -> Result<TxpoolContent> {
+        let all = self.all_transactions();
+        self.blocking_task_pool
+            .spawn(move || {
+                let AllPoolTransactions { pending, queued } = &*all;
+                TxpoolContent {
+                    pending: content_by_sender(pending, &filter),
+                    queued: content_by_sender(queued, &filter),
+                }
+            })
+            .await
+            .map_err(|_| EthApiError::InternalBlockingTaskError.into())
+    }
+
+    /// Applies the sender's pooled transactions in nonce order against its on-chain balance and
+    /// returns the transactions that would become underfunded.
+    fn cascade_risk(&self, sender: Address) -> Result<TxpoolCascadeRisk> {
+        let balance = self
+            .provider
+            .latest()
+            .and_then(|state| state.account_balance(sender))
+            .map_err(EthApiError::from)?
+            .unwrap_or_default();
+
+        let mut transactions = self.pool.get_transactions_by_sender(sender);
+        transactions.sort_unstable_by_key(|tx| tx.nonce());
+
+        let mut remaining = balance;
+        let at_risk_from =
+            transactions.iter().position(|tx| match remaining.checked_sub(tx.cost()) {
+                Some(rest) => {
+                    remaining = rest;
+                    false
+                }
+                None => true,
+            });
+
+        let Some(at_risk_from) = at_risk_from else {
+            return Ok(TxpoolCascadeRisk { sender, balance, ..Default::default() })
+        };
+
+        Ok(TxpoolCascadeRisk {
+            sender,
+            balance,
+            first_underfunded_nonce: Some(transactions[at_risk_from].nonce()),
+            at_risk: transactions[at_risk_from..].iter().map(|tx| *tx.hash()).collect(),
+        })
+    }
+
+    /// Counts the pooled transactions by the peer they were first received from.
+    fn contributions_by_peer(&self) -> TxpoolPeerContributions {
+        let sources = self.pool.transactions_received_from_peers();
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+
+        let mut contributions = TxpoolPeerContributions::default();
+        for tx in pending.iter().chain(queued.iter()) {
+            if !tx.origin.is_external() {
+                contributions.local += 1;
+            } else if let Some(peer) = sources.get(tx.hash()) {
+                *contributions.peers.entry(*peer).or_default() += 1;
+            } else {
+                contributions.unknown += 1;
+            }
+        }
+        contributions
+    }
+
+    /// Builds a bloom filter with the given number of bits over all pooled transaction hashes.
+    fn hash_bloom(&self, bits: usize) -> Result<TxpoolHashBloom> {
+        if bits == 0 || bits > MAX_HASH_BLOOM_BITS {
+            return Err(invalid_params_rpc_err(format!(
+                "bloom size must be between 1 and {MAX_HASH_BLOOM_BITS} bits"
+            )))
+        }
+        Ok(build_hash_bloom(&self.pool.pooled_transaction_hashes(), bits))
+    }
+
+    /// Returns the pooled transactions that collide with a transaction of the given block.
+    fn mined_collisions(&self, block_hash: B256) -> Result<Vec<TxpoolMinedCollision>> {
+        let block = self
+            .provider
+            .block_with_senders(block_hash.into(), TransactionVariant::WithHash)
+            .map_err(EthApiError::from)?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+
+        let mut collisions = Vec::new();
+        for (sender, tx) in block.transactions_with_sender() {
+            let nonce = tx.nonce();
+            let Some(pooled) = self.pool.get_transactions_by_sender_and_nonce(*sender, nonce)
+            else {
+                continue
+            };
+            if *pooled.hash() != tx.hash() {
+                collisions.push(TxpoolMinedCollision {
+                    sender: *sender,
+                    nonce,
+                    pool_hash: *pooled.hash(),
+                    mined_hash: tx.hash(),
+                });
+            }
+        }
+        Ok(collisions)
+    }
+
+    /// Computes `sum(effective_fee * gas_limit) / sum(gas_limit)` over the pending sub-pool.
+    fn gas_weighted_avg_fee(&self) -> U256 {
+        let base_fee = self.pool.block_info().pending_basefee;
+
+        let mut weighted_fees = U256::ZERO;
+        let mut total_gas = U256::ZERO;
+        for tx in self.pool.pending_transactions() {
+            let Some(tip) = tx.transaction.effective_tip_per_gas(base_fee) else { continue };
+            let gas_limit = U256::from(tx.gas_limit());
+            weighted_fees += U256::from(tip + base_fee as u128) * gas_limit;
+            total_gas += gas_limit;
+        }
+
+        if total_gas.is_zero() {
+            return U256::ZERO
+        }
+        weighted_fees / total_gas
+    }
+
+    /// Estimates the memory consumed by the pool from the sizes it tracks for its size limits.
+    fn memory_estimate(&self) -> TxpoolMemoryEstimate {
+        // Each pooled transaction is wrapped in a `ValidPoolTransaction` that is not accounted for
+        // in the tracked transaction sizes.
+        let overhead = std::mem::size_of::<ValidPoolTransaction<Pool::Transaction>>();
+        let size = self.pool.pool_size();
+
+        let pending = (size.pending_size + size.pending * overhead) as u64;
+        let queued = (size.basefee_size +
+            size.queued_size +
+            size.blob_size +
+            (size.basefee + size.queued + size.blob) * overhead) as u64;
+        let blob_store = self.pool.blob_store_size_hint().map(|size| size as u64);
+
+        TxpoolMemoryEstimate {
+            pending,
+            queued,
+            blob_store,
+            total: pending + queued + blob_store.unwrap_or_default(),
+        }
+    }
+
+    /// Counts the pooled transactions whose max fee per gas is below `min_fee`.
+    fn simulate_min_fee(&self, min_fee: U256) -> TxpoolMinFeeSimulation {
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+
+        let mut simulation = TxpoolMinFeeSimulation::default();
+        for (tx, is_pending) in
+            pending.iter().map(|tx| (tx, true)).chain(queued.iter().map(|tx| (tx, false)))
+        {
+            if U256::from(tx.max_fee_per_gas()) >= min_fee {
+                continue
+            }
+            if is_pending {
+                simulation.pending += 1;
+            } else {
+                simulation.queued += 1;
+            }
+            if simulation.sample.len() < MIN_FEE_SIMULATION_SAMPLE_SIZE {
+                simulation.sample.push(*tx.hash());
+            }
+        }
+        simulation
+    }
+
+    /// Returns `limit` transactions starting at `offset`, ordered by sender and nonce.
+    fn content_paginated(&self, offset: u64, limit: u64) -> Result<TxpoolContentPage> {
+        if limit > MAX_CONTENT_PAGE_SIZE {
+            return Err(invalid_params_rpc_err(format!(
+                "limit exceeds the maximum page size of {MAX_CONTENT_PAGE_SIZE} transactions"
+            )))
+        }
+
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+
+        let mut transactions = pending
+            .iter()
+            .map(|tx| (tx, true))
+            .chain(queued.iter().map(|tx| (tx, false)))
+            .collect::<Vec<_>>();
+        transactions.sort_unstable_by_key(|(tx, _)| (tx.sender(), tx.nonce()));
+
+        let offset = usize::try_from(offset).unwrap_or(usize::MAX);
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+
+        let mut page = TxpoolContentPage {
+            has_more: offset.saturating_add(limit) < transactions.len(),
+            ..Default::default()
+        };
+        for (tx, is_pending) in transactions.into_iter().skip(offset).take(limit) {
+            let content =
+                if is_pending { &mut page.content.pending } else { &mut page.content.queued };
             content.entry(tx.sender()).or_default().insert(
                 tx.nonce().to_string(),
-                reth_rpc_types_compat::transaction::from_recovered(tx.clone().into()),
+                reth_rpc_types_compat::transaction::from_recovered(tx.transaction.clone().into()),
             );
         }
+        Ok(page)
+    }
+
+    /// Returns the best transactions ordering without the transactions of the blocked senders.
+    fn ordered_excluding(&self, blocked: Vec<Address>) -> Result<Vec<TxHash>> {
+        if blocked.len() > MAX_ORDERING_BLOCKLIST_LEN {
+            return Err(invalid_params_rpc_err(format!(
+                "blocklist exceeds the maximum of {MAX_ORDERING_BLOCKLIST_LEN} senders"
+            )))
+        }
+
+        let blocked = blocked.into_iter().collect::<HashSet<_>>();
+        Ok(self
+            .pool
+            .best_transactions()
+            .filter(|tx| !blocked.contains(&tx.sender()))
+            .map(|tx| *tx.hash())
+            .collect())
+    }
+
+    /// Returns the `txpool_contentFrom` response for each of the given addresses.
+    async fn content_from_many(
+        &self,
+        addresses: Vec<Address>,
+    ) -> Result<BTreeMap<Address, TxpoolContentFrom>> {
+        if addresses.len() > MAX_CONTENT_FROM_MANY_LEN {
+            return Err(invalid_params_rpc_err(format!(
+                "request exceeds the maximum of {MAX_CONTENT_FROM_MANY_LEN} addresses"
+            )))
+        }
+
+        let addresses = addresses.into_iter().collect::<HashSet<_>>();
+        let senders = addresses.clone();
+        let mut content = self.content_filtered(move |tx| senders.contains(&tx.sender())).await?;
+        Ok(addresses.into_iter().map(|address| (address, content.remove_from(&address))).collect())
+    }
+
+    /// Returns the sorted pooled nonces of every sender that has queued transactions.
+    fn queued_sender_nonces(&self) -> BTreeMap<Address, Vec<u64>> {
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+
+        let mut nonces = BTreeMap::<Address, Vec<u64>>::new();
+        for tx in queued {
+            nonces.entry(tx.sender()).or_default().push(tx.nonce());
+        }
+        for tx in pending {
+            if let Some(sender_nonces) = nonces.get_mut(&tx.sender()) {
+                sender_nonces.push(tx.nonce());
+            }
+        }
+        for sender_nonces in nonces.values_mut() {
+            sender_nonces.sort_unstable();
+            sender_nonces.dedup();
+        }
+        nonces
+    }
+
+    /// Returns the senders for which a single filler transaction unblocks all their transactions.
+    fn gap_blocked_senders(&self) -> Result<Vec<TxpoolGapBlockedSender>> {
+        let state = self.provider.latest().map_err(EthApiError::from)?;
+        let mut blocked = Vec::new();
+        for (sender, sender_nonces) in self.queued_sender_nonces() {
+            let on_chain_nonce =
+                state.account_nonce(sender).map_err(EthApiError::from)?.unwrap_or_default();
 
-        let AllPoolTransactions { pending, queued } = self.pool.all_transactions();
+            // walk the pooled nonces from the on-chain nonce and record every gap
+            let mut expected = on_chain_nonce;
+            let mut gap = None;
+            let mut gaps = 0u64;
+            for nonce in sender_nonces.iter().copied().filter(|nonce| *nonce >= on_chain_nonce) {
+                if nonce > expected {
+                    gaps += nonce - expected;
+                    gap.get_or_insert(expected);
+                }
+                expected = nonce + 1;
+            }
+
+            if let (1, Some(missing_nonce)) = (gaps, gap) {
+                let unblocks =
+                    sender_nonces.iter().filter(|nonce| **nonce > missing_nonce).count() as u64;
+                blocked.push(TxpoolGapBlockedSender { sender, missing_nonce, unblocks });
+            }
+        }
+        Ok(blocked)
+    }
 
-        let mut content = TxpoolContent::default();
-        for pending in pending {
-            insert(&pending.transaction, &mut content.pending);
+    /// Returns the clusters of pending transactions sharing the same max fee per gas.
+    fn duplicate_fee_clusters(&self, min_cluster: usize) -> Vec<TxpoolFeeCluster> {
+        let mut clusters = HashMap::<u128, Vec<TxHash>>::new();
+        for tx in self.pool.pending_transactions() {
+            clusters.entry(tx.max_fee_per_gas()).or_default().push(*tx.hash());
         }
-        for queued in queued {
-            insert(&queued.transaction, &mut content.queued);
+
+        let mut clusters = clusters
+            .into_iter()
+            .filter(|(_, hashes)| hashes.len() >= min_cluster)
+            .map(|(max_fee_per_gas, hashes)| TxpoolFeeCluster { max_fee_per_gas, hashes })
+            .collect::<Vec<_>>();
+        clusters.sort_unstable_by(|a, b| {
+            b.hashes.len().cmp(&a.hashes.len()).then(b.max_fee_per_gas.cmp(&a.max_fee_per_gas))
+        });
+        clusters
+    }
+
+    /// Returns the queued nonces of each sender that follow a missing nonce.
+    fn nonce_gaps(&self) -> Result<BTreeMap<Address, TxpoolNonceGaps>> {
+        let state = self.provider.latest().map_err(EthApiError::from)?;
+        let mut gaps = BTreeMap::new();
+        for (sender, sender_nonces) in self.queued_sender_nonces() {
+            let next_nonce =
+                state.account_nonce(sender).map_err(EthApiError::from)?.unwrap_or_default();
+
+            // every nonce after the first gap is stuck
+            let mut expected = next_nonce;
+            let gapped_nonces = sender_nonces
+                .into_iter()
+                .filter(|nonce| *nonce >= next_nonce)
+                .skip_while(|nonce| {
+                    let contiguous = *nonce == expected;
+                    expected += 1;
+                    contiguous
+                })
+                .collect::<Vec<_>>();
+
+            if !gapped_nonces.is_empty() {
+                gaps.insert(sender, TxpoolNonceGaps { next_nonce, gapped_nonces });
+            }
         }
+        Ok(gaps)
+    }
+
+    /// Returns the first eligible block of all pooled transactions, ordered by sender and nonce.
+    fn first_eligible_blocks(&self) -> Vec<TxpoolFirstEligibleBlock> {
+        let first_eligible = self.pool.transactions_first_eligible_blocks();
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
 
+        let mut entries = pending
+            .iter()
+            .chain(queued.iter())
+            .map(|tx| TxpoolFirstEligibleBlock {
+                hash: *tx.hash(),
+                sender: tx.sender(),
+                nonce: tx.nonce(),
+                first_eligible_block: first_eligible.get(tx.hash()).copied(),
+            })
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|entry| (entry.sender, entry.nonce));
+        entries
+    }
+
+    /// Returns the pool content with the pending transactions ranked by effective tip.
+    fn content_ranked(&self) -> TxpoolContentRanked {
+        let base_fee = self.pool.block_info().pending_basefee;
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+
+        let mut ranked = pending
+            .iter()
+            .map(|tx| TxpoolRankedTransaction {
+                sender: tx.sender(),
+                nonce: tx.nonce(),
+                effective_tip: tx.transaction.effective_tip_per_gas(base_fee).unwrap_or_default(),
+                transaction: reth_rpc_types_compat::transaction::from_recovered(
+                    tx.transaction.clone().into(),
+                ),
+            })
+            .collect::<Vec<_>>();
+        ranked.sort_by(|a, b| {
+            b.effective_tip
+                .cmp(&a.effective_tip)
+                .then((a.sender, a.nonce).cmp(&(b.sender, b.nonce)))
+        });
+
+        let mut content = TxpoolContentRanked { pending: ranked, ..Default::default() };
+        for tx in queued.iter() {
+            content.queued.entry(tx.sender()).or_default().insert(
+                tx.nonce().to_string(),
+                reth_rpc_types_compat::transaction::from_recovered(tx.transaction.clone().into()),
+            );
+        }
         content
     }
+
+    /// Returns the effective gas price of the pooled transaction at the pending base fee.
+    fn effective_gas_price(&self, hash: B256) -> Result<U256> {
+        let tx = self.pool.get(&hash).ok_or(EthApiError::TransactionNotFound)?;
+        let base_fee = self.pool.block_info().pending_basefee;
+        let tx: TransactionSignedEcRecovered = tx.transaction.clone().into();
+        Ok(U256::from(tx.effective_gas_price(Some(base_fee))))
+    }
+
+    /// Returns the status of the transaction with the given hash from the pool's classification.
+    fn tx_status(&self, hash: B256) -> TxpoolTxStatus {
+        let Some(placement) = self.pool.transaction_placement(&hash) else {
+            return TxpoolTxStatus::NotFound
+        };
+        if placement.subpool.is_pending() {
+            return TxpoolTxStatus::Pending
+        }
+
+        let reasons = placement
+            .queued_reasons
+            .into_iter()
+            .map(|reason| match reason {
+                QueuedReason::NonceGap => TxpoolQueuedReason::NonceGap,
+                QueuedReason::ParkedAncestor => TxpoolQueuedReason::ParkedAncestor,
+                QueuedReason::InsufficientBalance => TxpoolQueuedReason::InsufficientBalance,
+                QueuedReason::ExceedsBlockGasLimit => TxpoolQueuedReason::ExceedsBlockGasLimit,
+                QueuedReason::InsufficientBaseFee => TxpoolQueuedReason::InsufficientBaseFee,
+                QueuedReason::InsufficientBlobFee => TxpoolQueuedReason::InsufficientBlobFee,
+            })
+            .collect();
+        TxpoolTxStatus::Queued { reasons }
+    }
+
+    /// Returns the pool churn within the last `window_secs` seconds.
+    fn churn_stats(&self, window_secs: u64) -> Result<TxpoolChurnStats> {
+        let window = Duration::from_secs(window_secs);
+        if window > MAX_CHURN_WINDOW {
+            return Err(invalid_params_rpc_err(format!(
+                "window must be at most {} seconds",
+                MAX_CHURN_WINDOW.as_secs()
+            )))
+        }
+        let ChurnStats { new, replaced, removed } = self.pool.churn_stats(window);
+        Ok(TxpoolChurnStats { new_count: new, replaced_count: replaced, removed_count: removed })
+    }
+
+    /// Returns the pooled transactions with a gas limit above `min_gas` and at most
+    /// [`MAX_GAS_ANOMALY_INPUT_LEN`] bytes of calldata, ordered by descending gas limit.
+    fn gas_anomalies(&self, min_gas: u64) -> Vec<TxpoolGasAnomaly> {
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+
+        let mut anomalies = pending
+            .iter()
+            .chain(queued.iter())
+            .filter(|tx| {
+                tx.gas_limit() > min_gas &&
+                    tx.transaction.input().len() <= MAX_GAS_ANOMALY_INPUT_LEN
+            })
+            .map(|tx| TxpoolGasAnomaly {
+                hash: *tx.hash(),
+                sender: tx.sender(),
+                nonce: tx.nonce(),
+                gas_limit: tx.gas_limit(),
+                input_len: tx.transaction.input().len() as u64,
+            })
+            .collect::<Vec<_>>();
+        anomalies.sort_unstable_by(|a, b| b.gas_limit.cmp(&a.gas_limit).then(a.hash.cmp(&b.hash)));
+        anomalies
+    }
+
+    /// Estimates the inclusion delay of a transaction paying the given effective gas price from the
+    /// pending transactions that pay more.
+    fn estimate_wait(&self, fee: U256, blocks_per_sec: f64) -> Result<TxpoolWaitEstimate> {
+        if !blocks_per_sec.is_finite() || blocks_per_sec <= 0.0 {
+            return Err(invalid_params_rpc_err(format!(
+                "blocks per second must be positive, got {blocks_per_sec}"
+            )))
+        }
+
+        let block_info = self.pool.block_info();
+        let base_fee = block_info.pending_basefee;
+        let block_gas_limit = self
+            .provider
+            .header_by_number(block_info.last_seen_block_number)
+            .map_err(EthApiError::from)?
+            .map_or(ETHEREUM_BLOCK_GAS_LIMIT, |header| header.gas_limit)
+            .max(1);
+
+        let mut estimate = TxpoolWaitEstimate::default();
+        for tx in self.pool.pending_transactions() {
+            let Some(tip) = tx.transaction.effective_tip_per_gas(base_fee) else { continue };
+            if U256::from(tip + base_fee as u128) > fee {
+                estimate.transactions_ahead += 1;
+                estimate.gas_ahead += tx.gas_limit();
+            }
+        }
+
+        estimate.blocks = estimate.gas_ahead / block_gas_limit + 1;
+        estimate.wait_secs = (estimate.blocks as f64 / blocks_per_sec).ceil() as u64;
+        Ok(estimate)
+    }
+
+    /// Looks up the given hashes with a single pass over the pooled transactions.
+    fn transactions_by_hash(
+        &self,
+        hashes: Vec<B256>,
+    ) -> Result<Vec<Option<TxpoolTransactionByHash>>> {
+        if hashes.len() > MAX_TRANSACTIONS_BY_HASH_LEN {
+            return Err(invalid_params_rpc_err(format!(
+                "request exceeds the maximum of {MAX_TRANSACTIONS_BY_HASH_LEN} hashes"
+            )))
+        }
+
+        let requested = hashes.iter().collect::<HashSet<_>>();
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+
+        let mut found = HashMap::with_capacity(requested.len());
+        for (transactions, sub_pool) in
+            [(pending, TxpoolSubPool::Pending), (queued, TxpoolSubPool::Queued)]
+        {
+            for tx in transactions.iter().filter(|tx| requested.contains(tx.hash())) {
+                found.insert(*tx.hash(), (tx, sub_pool));
+            }
+        }
+
+        Ok(hashes
+            .iter()
+            .map(|hash| {
+                found.get(hash).map(|(tx, sub_pool)| TxpoolTransactionByHash {
+                    transaction: reth_rpc_types_compat::transaction::from_recovered(
+                        tx.transaction.clone().into(),
+                    ),
+                    sub_pool: *sub_pool,
+                })
+            })
+            .collect())
+    }
+
+    /// Sums the blob gas and execution gas of the pending sub-pool.
+    fn blob_gas_ratio(&self) -> TxpoolBlobGasRatio {
+        let mut total_blob_gas = 0u64;
+        let mut total_execution_gas = 0u64;
+        for tx in self.pool.pending_transactions() {
+            total_blob_gas = total_blob_gas
+                .saturating_add(tx.transaction.blob_count() as u64 * DATA_GAS_PER_BLOB);
+            total_execution_gas = total_execution_gas.saturating_add(tx.gas_limit());
+        }
+
+        let blob_ratio = if total_execution_gas == 0 {
+            0.0
+        } else {
+            total_blob_gas as f64 / total_execution_gas as f64
+        };
+        TxpoolBlobGasRatio { total_blob_gas, total_execution_gas, blob_ratio }
+    }
+
+    /// Draws a seeded random sample of up to `n` pending transactions without replacement.
+    ///
+    /// Pending transactions are ordered by hash before sampling and the sample is returned in that
+    /// order, so the result only depends on the seed and the pool content.
+    fn sample(&self, n: usize, seed: u64) -> Vec<Transaction> {
+        let mut pending = self.pool.pending_transactions();
+        pending.sort_unstable_by(|a, b| a.hash().cmp(b.hash()));
+
+        let amount = n.min(MAX_SAMPLE_LEN).min(pending.len());
+        let mut indices =
+            rand::seq::index::sample(&mut StdRng::seed_from_u64(seed), pending.len(), amount)
+                .into_vec();
+        indices.sort_unstable();
+
+        indices
+            .into_iter()
+            .map(|index| {
+                reth_rpc_types_compat::transaction::from_recovered(
+                    pending[index].transaction.clone().into(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the queued transactions that only miss the current base fee by less than
+    /// `drop_pct` percent.
+    fn unlock_on_basefee_drop(&self, drop_pct: u8) -> Result<TxpoolBasefeeDropUnlock> {
+        if !(1..=100).contains(&drop_pct) {
+            return Err(invalid_params_rpc_err(format!(
+                "drop percentage must be within 1..=100, got {drop_pct}"
+            )))
+        }
+
+        let base_fee = self.pool.block_info().pending_basefee;
+        let hypothetical_base_fee = (base_fee as u128 * (100 - drop_pct as u128) / 100) as u64;
+
+        let all = self.all_transactions();
+        let mut unlocked = all
+            .queued
+            .iter()
+            .filter(|tx| {
+                let max_fee = tx.max_fee_per_gas();
+                max_fee < base_fee as u128 && max_fee >= hypothetical_base_fee as u128
+            })
+            .collect::<Vec<_>>();
+        unlocked.sort_unstable_by_key(|tx| (tx.sender(), tx.nonce()));
+
+        let transactions = unlocked
+            .into_iter()
+            .map(|tx| {
+                reth_rpc_types_compat::transaction::from_recovered(tx.transaction.clone().into())
+            })
+            .collect();
+        Ok(TxpoolBasefeeDropUnlock { base_fee, hypothetical_base_fee, transactions })
+    }
+
+    /// Counts the pending transactions per priority fee range given by `bucket_bounds`.
+    fn priority_fee_distribution(
+        &self,
+        bucket_bounds: Vec<U256>,
+    ) -> Result<TxpoolPriorityFeeDistribution> {
+        if bucket_bounds.len() > MAX_PRIORITY_FEE_BUCKET_BOUNDS {
+            return Err(invalid_params_rpc_err(format!(
+                "too many bucket bounds, at most {MAX_PRIORITY_FEE_BUCKET_BOUNDS} are allowed"
+            )))
+        }
+        if bucket_bounds.windows(2).any(|bounds| bounds[0] >= bounds[1]) {
+            return Err(invalid_params_rpc_err("bucket bounds must be strictly increasing"))
+        }
+
+        let mut distribution = TxpoolPriorityFeeDistribution {
+            buckets: std::iter::once(U256::ZERO)
+                .chain(bucket_bounds.iter().copied())
+                .zip(bucket_bounds.iter().copied().map(Some).chain(std::iter::once(None)))
+                .map(|(min, max)| TxpoolPriorityFeeBucket { min, max, count: 0 })
+                .collect(),
+            legacy: 0,
+        };
+        for tx in self.pool.pending_transactions() {
+            let Some(priority_fee) = tx.transaction.max_priority_fee_per_gas() else {
+                distribution.legacy += 1;
+                continue
+            };
+            // The number of bounds at or below the fee is the index of its bucket
+            let index = bucket_bounds.partition_point(|bound| *bound <= U256::from(priority_fee));
+            distribution.buckets[index].count += 1;
+        }
+        Ok(distribution)
+    }
+
+    /// Orders the sender's pooled transactions by nonce and links each one to the transaction at
+    /// the previous nonce, starting from the on-chain nonce.
+    fn dependency_graph(&self, sender: Address) -> Result<TxpoolDependencyGraph> {
+        let on_chain_nonce = self
+            .provider
+            .latest()
+            .and_then(|state| state.account_nonce(sender))
+            .map_err(EthApiError::from)?
+            .unwrap_or_default();
+
+        let mut pooled = self.pool.get_transactions_by_sender(sender);
+        // Transactions below the on-chain nonce were mined and are about to be removed
+        pooled.retain(|tx| tx.nonce() >= on_chain_nonce);
+        pooled.sort_unstable_by_key(|tx| tx.nonce());
+
+        let mut transactions = Vec::<TxpoolDependencyNode>::with_capacity(pooled.len());
+        let mut gapped = false;
+        let mut expected_nonce = on_chain_nonce;
+        for tx in &pooled {
+            let depends_on = transactions
+                .last()
+                .filter(|previous| previous.nonce + 1 == tx.nonce())
+                .map(|previous| previous.hash);
+            gapped |= tx.nonce() != expected_nonce;
+            expected_nonce = tx.nonce() + 1;
+            transactions.push(TxpoolDependencyNode {
+                hash: *tx.hash(),
+                nonce: tx.nonce(),
+                depends_on,
+                gapped,
+            });
+        }
+
+        let first_includable =
+            transactions.first().filter(|node| node.nonce == on_chain_nonce).map(|node| node.hash);
+        Ok(TxpoolDependencyGraph { sender, on_chain_nonce, first_includable, transactions })
+    }
+
+    /// Returns the nonces of each sender at which the max fee per gas is lower than the max fee
+    /// of the transaction with the preceding pooled nonce.
+    fn nonmonotonic_fee_queues(&self) -> BTreeMap<Address, Vec<u64>> {
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+
+        let mut queues = BTreeMap::<Address, Vec<(u64, u128)>>::new();
+        for tx in pending.iter().chain(queued.iter()) {
+            queues.entry(tx.sender()).or_default().push((tx.nonce(), tx.max_fee_per_gas()));
+        }
+
+        queues
+            .into_iter()
+            .filter_map(|(sender, mut queue)| {
+                queue.sort_unstable();
+                let decreases = queue
+                    .windows(2)
+                    .filter(|pair| pair[1].1 < pair[0].1)
+                    .map(|pair| pair[1].0)
+                    .collect::<Vec<_>>();
+                (!decreases.is_empty()).then_some((sender, decreases))
+            })
+            .collect()
+    }
+
+    /// Counts the pooled transactions that arrived in each `bucket_secs` interval of the
+    /// `window_secs` seconds before `now`, oldest bucket first.
+    fn arrival_timeline(
+        &self,
+        now: Instant,
+        window_secs: u64,
+        bucket_secs: u64,
+    ) -> Result<Vec<u64>> {
+        if bucket_secs == 0 || window_secs % bucket_secs != 0 {
+            return Err(invalid_params_rpc_err(format!(
+                "bucket of {bucket_secs} seconds does not divide the window of {window_secs} seconds"
+            )))
+        }
+        let buckets = window_secs / bucket_secs;
+        if buckets > MAX_ARRIVAL_TIMELINE_BUCKETS {
+            return Err(invalid_params_rpc_err(format!(
+                "window must span at most {MAX_ARRIVAL_TIMELINE_BUCKETS} buckets"
+            )))
+        }
+
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+
+        let mut timeline = vec![0; buckets as usize];
+        for tx in pending.iter().chain(queued.iter()) {
+            // buckets are counted back from `now`, the last bucket holds the newest arrivals
+            let age = now.saturating_duration_since(tx.timestamp).as_secs();
+            if age < window_secs {
+                timeline[(buckets - 1 - age / bucket_secs) as usize] += 1;
+            }
+        }
+        Ok(timeline)
+    }
+
+    /// Returns the pooled transactions with more than [`MAX_BLOBS_PER_BLOCK`] blobs, the limit
+    /// the pool validates blob transactions against.
+    fn oversized_blob_txs(&self) -> Vec<TxpoolOversizedBlobTx> {
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+
+        pending
+            .iter()
+            .chain(queued.iter())
+            .filter(|tx| tx.transaction.blob_count() > MAX_BLOBS_PER_BLOCK)
+            .map(|tx| TxpoolOversizedBlobTx {
+                hash: *tx.hash(),
+                sender: tx.sender(),
+                nonce: tx.nonce(),
+                blob_count: tx.transaction.blob_count() as u64,
+                max_blob_count: MAX_BLOBS_PER_BLOCK as u64,
+            })
+            .collect()
+    }
+
+    /// Returns up to `limit` pending transactions ordered by descending effective tip at the
+    /// pending base fee and then by hash.
+    fn by_profitability(&self, limit: usize) -> Vec<TxpoolRankedTransaction> {
+        let base_fee = self.pool.block_info().pending_basefee;
+        let mut pending = self
+            .pool
+            .pending_transactions()
+            .into_iter()
+            .map(|tx| (tx.transaction.effective_tip_per_gas(base_fee).unwrap_or_default(), tx))
+            .collect::<Vec<_>>();
+        pending.sort_unstable_by(|(a_tip, a), (b_tip, b)| {
+            b_tip.cmp(a_tip).then_with(|| a.hash().cmp(b.hash()))
+        });
+
+        pending
+            .into_iter()
+            .take(limit.min(MAX_BY_PROFITABILITY_LEN))
+            .map(|(effective_tip, tx)| TxpoolRankedTransaction {
+                sender: tx.sender(),
+                nonce: tx.nonce(),
+                effective_tip,
+                transaction: reth_rpc_types_compat::transaction::from_recovered(
+                    tx.transaction.clone().into(),
+                ),
+            })
+            .collect()
+    }
 }
 
 #[async_trait]
-impl<Pool> TxPoolApiServer for TxPoolApi<Pool>
+impl<Provider, Pool> TxPoolApiServer for TxPoolApi<Provider, Pool>
 where
+    Provider: StateProviderFactory + BlockReader + 'static,
     Pool: TransactionPool + 'static,
 {
     /// Returns the number of transactions currently pending for inclusion in the next block(s), as
@@ -68,13 +945,16 @@ where
     /// Handler for `txpool_status`
     async fn txpool_status(&self) -> Result<TxpoolStatus> {
         trace!(target: "rpc::eth", "Serving txpool_status");
-        let all = self.pool.all_transactions();
+        let all = self.all_transactions();
         Ok(TxpoolStatus { pending: all.pending.len() as u64, queued: all.queued.len() as u64 })
     }
 
     /// Returns a summary of all the transactions currently pending for inclusion in the next
     /// block(s), as well as the ones that are being scheduled for future execution only.
     ///
+    /// The reported gas price is the effective gas price at the pending base fee, legacy
+    /// transactions report their gas price.
+    ///
     /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_inspect) for more details
     ///
     /// Handler for `txpool_inspect`
@@ -84,6 +964,7 @@ where
         #[inline]
         fn insert<T: PoolTransaction>(
             tx: &T,
+            base_fee: u64,
             inspect: &mut BTreeMap<Address, BTreeMap<String, TxpoolInspectSummary>>,
         ) {
             let entry = inspect.entry(tx.sender()).or_default();
@@ -94,20 +975,23 @@ where
                     to: tx.to(),
                     value: tx.value(),
                     gas: tx.gas_limit() as u128,
-                    gas_price: tx.transaction.max_fee_per_gas(),
+                    gas_price: tx.transaction.effective_gas_price(Some(base_fee)),
                 },
             );
         }
 
-        let AllPoolTransactions { pending, queued } = self.pool.all_transactions();
+        let base_fee = self.pool.block_info().pending_basefee;
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
 
+        self.metrics.inspect_transactions.record((pending.len() + queued.len()) as f64);
         Ok(TxpoolInspect {
             pending: pending.iter().fold(Default::default(), |mut acc, tx| {
-                insert(&tx.transaction, &mut acc);
+                insert(&tx.transaction, base_fee, &mut acc);
                 acc
             }),
             queued: queued.iter().fold(Default::default(), |mut acc, tx| {
-                insert(&tx.transaction, &mut acc);
+                insert(&tx.transaction, base_fee, &mut acc);
                 acc
             }),
         })
@@ -120,7 +1004,7 @@ where
     /// Handler for `txpool_contentFrom`
     async fn txpool_content_from(&self, from: Address) -> Result<TxpoolContentFrom> {
         trace!(target: "rpc::eth", ?from, "Serving txpool_contentFrom");
-        Ok(self.content().remove_from(&from))
+        Ok(self.content().await?.remove_from(&from))
     }
 
     /// Returns the details of all transactions currently pending for inclusion in the next
@@ -130,12 +1014,1118 @@ where
     /// Handler for `txpool_content`
     async fn txpool_content(&self) -> Result<TxpoolContent> {
         trace!(target: "rpc::eth", "Serving txpool_content");
-        Ok(self.content())
+        let content = self.content().await?;
+        let transactions =
+            content.pending.values().chain(content.queued.values()).map(BTreeMap::len);
+        self.metrics.content_transactions.record(transactions.sum::<usize>() as f64);
+        Ok(content)
+    }
+
+    /// Handler for `txpool_cascadeRisk`
+    async fn txpool_cascade_risk(&self, address: Address) -> Result<TxpoolCascadeRisk> {
+        trace!(target: "rpc::eth", ?address, "Serving txpool_cascadeRisk");
+        self.cascade_risk(address)
+    }
+
+    /// Handler for `txpool_contributionsByPeer`
+    async fn txpool_contributions_by_peer(&self) -> Result<TxpoolPeerContributions> {
+        trace!(target: "rpc::eth", "Serving txpool_contributionsByPeer");
+        Ok(self.contributions_by_peer())
+    }
+
+    /// Handler for `txpool_hashBloom`
+    async fn txpool_hash_bloom(&self, bits: usize) -> Result<TxpoolHashBloom> {
+        trace!(target: "rpc::eth", bits, "Serving txpool_hashBloom");
+        self.hash_bloom(bits)
+    }
+
+    /// Handler for `txpool_minedCollisions`
+    async fn txpool_mined_collisions(&self, block_hash: B256) -> Result<Vec<TxpoolMinedCollision>> {
+        trace!(target: "rpc::eth", ?block_hash, "Serving txpool_minedCollisions");
+        self.mined_collisions(block_hash)
+    }
+
+    /// Handler for `txpool_gasWeightedAvgFee`
+    async fn txpool_gas_weighted_avg_fee(&self) -> Result<U256> {
+        trace!(target: "rpc::eth", "Serving txpool_gasWeightedAvgFee");
+        Ok(self.gas_weighted_avg_fee())
+    }
+
+    /// Handler for `txpool_memoryEstimate`
+    async fn txpool_memory_estimate(&self) -> Result<TxpoolMemoryEstimate> {
+        trace!(target: "rpc::eth", "Serving txpool_memoryEstimate");
+        Ok(self.memory_estimate())
+    }
+
+    /// Handler for `txpool_simulateMinFee`
+    async fn txpool_simulate_min_fee(&self, new_min_fee: U256) -> Result<TxpoolMinFeeSimulation> {
+        trace!(target: "rpc::eth", %new_min_fee, "Serving txpool_simulateMinFee");
+        Ok(self.simulate_min_fee(new_min_fee))
+    }
+
+    /// Handler for `txpool_contentPaginated`
+    async fn txpool_content_paginated(&self, offset: u64, limit: u64) -> Result<TxpoolContentPage> {
+        trace!(target: "rpc::eth", offset, limit, "Serving txpool_contentPaginated");
+        self.content_paginated(offset, limit)
+    }
+
+    /// Handler for `txpool_contentFiltered`
+    async fn txpool_content_filtered(&self, min_gas_price: U256) -> Result<TxpoolContent> {
+        trace!(target: "rpc::eth", %min_gas_price, "Serving txpool_contentFiltered");
+        // for legacy transactions the max fee per gas is the gas price
+        self.content_filtered(move |tx| U256::from(tx.max_fee_per_gas()) >= min_gas_price).await
+    }
+
+    /// Handler for `txpool_orderedExcluding`
+    async fn txpool_ordered_excluding(&self, blocked: Vec<Address>) -> Result<Vec<TxHash>> {
+        trace!(target: "rpc::eth", blocked = blocked.len(), "Serving txpool_orderedExcluding");
+        self.ordered_excluding(blocked)
+    }
+
+    /// Handler for `txpool_contentFromMany`
+    async fn txpool_content_from_many(
+        &self,
+        addresses: Vec<Address>,
+    ) -> Result<BTreeMap<Address, TxpoolContentFrom>> {
+        trace!(target: "rpc::eth", addresses = addresses.len(), "Serving txpool_contentFromMany");
+        self.content_from_many(addresses).await
+    }
+
+    /// Handler for `txpool_subscribe`
+    async fn txpool_subscribe(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: Option<TxpoolSubscriptionFilter>,
+    ) -> jsonrpsee::core::SubscriptionResult {
+        trace!(target: "rpc::eth", ?filter, "Serving txpool_subscribe");
+        let filter = filter.unwrap_or_default();
+        let stream = self
+            .pool
+            .new_pending_pool_transactions_listener()
+            .filter(move |event| {
+                let tx = &event.transaction;
+                futures::future::ready(filter.matches(tx.sender(), tx.priority_fee_or_price()))
+            })
+            .map(|event| {
+                reth_rpc_types_compat::transaction::from_recovered(
+                    event.transaction.to_recovered_transaction(),
+                )
+            });
+
+        let sink = pending.accept().await?;
+        // the stream, and with it the pool listener, is dropped once the subscription is closed
+        self.subscription_task_spawner.spawn(Box::pin(async move {
+            let _ = pipe_from_stream(sink, stream).await;
+        }));
+
+        Ok(())
+    }
+
+    /// Handler for `txpool_gapBlockedSenders`
+    async fn txpool_gap_blocked_senders(&self) -> Result<Vec<TxpoolGapBlockedSender>> {
+        trace!(target: "rpc::eth", "Serving txpool_gapBlockedSenders");
+        self.gap_blocked_senders()
+    }
+
+    /// Handler for `txpool_inspectBlobs`
+    async fn txpool_inspect_blobs(&self) -> Result<TxpoolInspectBlobs> {
+        trace!(target: "rpc::eth", "Serving txpool_inspectBlobs");
+
+        #[inline]
+        fn insert<T: PoolTransaction>(
+            tx: &T,
+            inspect: &mut BTreeMap<Address, BTreeMap<String, TxpoolBlobSummary>>,
+        ) {
+            let Some(max_fee_per_blob_gas) = tx.max_fee_per_blob_gas() else { return };
+            let tx: TransactionSignedEcRecovered = tx.clone().into();
+            let blob_count = tx.blob_versioned_hashes().map_or(0, |hashes| hashes.len() as u64);
+            inspect.entry(tx.signer()).or_default().insert(
+                tx.nonce().to_string(),
+                TxpoolBlobSummary { max_fee_per_blob_gas, blob_count },
+            );
+        }
+
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+
+        let mut inspect = TxpoolInspectBlobs::default();
+        for tx in pending.iter().filter(|tx| tx.is_eip4844()) {
+            insert(&tx.transaction, &mut inspect.pending);
+        }
+        for tx in queued.iter().filter(|tx| tx.is_eip4844()) {
+            insert(&tx.transaction, &mut inspect.queued);
+        }
+        Ok(inspect)
+    }
+
+    /// Handler for `txpool_count`
+    async fn txpool_count(&self) -> Result<TxpoolStatus> {
+        trace!(target: "rpc::eth", "Serving txpool_count");
+        let size = self.pool.pool_size();
+        // matches `txpool_status`, which counts the basefee sub-pool as queued
+        Ok(TxpoolStatus {
+            pending: size.pending as u64,
+            queued: (size.basefee + size.queued) as u64,
+        })
+    }
+
+    /// Handler for `txpool_contentDigest`
+    async fn txpool_content_digest(&self) -> Result<TxpoolContentDigest> {
+        trace!(target: "rpc::eth", "Serving txpool_contentDigest");
+        Ok(content_digest(self.pool.pooled_transaction_hashes()))
+    }
+
+    /// Handler for `txpool_statusBySender`
+    async fn txpool_status_by_sender(&self, from: Address) -> Result<TxpoolStatus> {
+        trace!(target: "rpc::eth", ?from, "Serving txpool_statusBySender");
+        let all = self.all_transactions();
+        let AllPoolTransactions { pending, queued } = &*all;
+        Ok(TxpoolStatus {
+            pending: pending.iter().filter(|tx| tx.sender() == from).count() as u64,
+            queued: queued.iter().filter(|tx| tx.sender() == from).count() as u64,
+        })
+    }
+
+    /// Handler for `txpool_duplicateFeeClusters`
+    async fn txpool_duplicate_fee_clusters(
+        &self,
+        min_cluster: usize,
+    ) -> Result<Vec<TxpoolFeeCluster>> {
+        trace!(target: "rpc::eth", min_cluster, "Serving txpool_duplicateFeeClusters");
+        Ok(self.duplicate_fee_clusters(min_cluster))
+    }
+
+    /// Handler for `txpool_nonceGaps`
+    async fn txpool_nonce_gaps(&self) -> Result<BTreeMap<Address, TxpoolNonceGaps>> {
+        trace!(target: "rpc::eth", "Serving txpool_nonceGaps");
+        self.nonce_gaps()
+    }
+
+    /// Handler for `txpool_firstEligibleBlock`
+    async fn txpool_first_eligible_block(&self) -> Result<Vec<TxpoolFirstEligibleBlock>> {
+        trace!(target: "rpc::eth", "Serving txpool_firstEligibleBlock");
+        Ok(self.first_eligible_blocks())
+    }
+
+    /// Handler for `txpool_contentRanked`
+    async fn txpool_content_ranked(&self) -> Result<TxpoolContentRanked> {
+        trace!(target: "rpc::eth", "Serving txpool_contentRanked");
+        Ok(self.content_ranked())
+    }
+
+    /// Handler for `txpool_effectiveGasPrice`
+    async fn txpool_effective_gas_price(&self, hash: B256) -> Result<U256> {
+        trace!(target: "rpc::eth", ?hash, "Serving txpool_effectiveGasPrice");
+        self.effective_gas_price(hash)
+    }
+
+    /// Handler for `txpool_locals`
+    async fn txpool_locals(&self) -> Result<TxpoolContent> {
+        trace!(target: "rpc::eth", "Serving txpool_locals");
+        self.content_filtered(|tx| tx.origin.is_local()).await
+    }
+
+    /// Handler for `txpool_txStatus`
+    async fn txpool_tx_status(&self, hash: B256) -> Result<TxpoolTxStatus> {
+        trace!(target: "rpc::eth", ?hash, "Serving txpool_txStatus");
+        Ok(self.tx_status(hash))
+    }
+
+    /// Handler for `txpool_churnStats`
+    async fn txpool_churn_stats(&self, window_secs: u64) -> Result<TxpoolChurnStats> {
+        trace!(target: "rpc::eth", window_secs, "Serving txpool_churnStats");
+        self.churn_stats(window_secs)
+    }
+
+    /// Handler for `txpool_gasAnomalies`
+    async fn txpool_gas_anomalies(&self, min_gas: u64) -> Result<Vec<TxpoolGasAnomaly>> {
+        trace!(target: "rpc::eth", min_gas, "Serving txpool_gasAnomalies");
+        Ok(self.gas_anomalies(min_gas))
+    }
+
+    /// Handler for `txpool_selfSends`
+    async fn txpool_self_sends(&self) -> Result<TxpoolContent> {
+        trace!(target: "rpc::eth", "Serving txpool_selfSends");
+        self.content_filtered(|tx| tx.transaction.to() == Some(tx.sender())).await
+    }
+
+    /// Handler for `txpool_estimateWait`
+    async fn txpool_estimate_wait(
+        &self,
+        fee: U256,
+        blocks_per_sec: f64,
+    ) -> Result<TxpoolWaitEstimate> {
+        trace!(target: "rpc::eth", %fee, blocks_per_sec, "Serving txpool_estimateWait");
+        self.estimate_wait(fee, blocks_per_sec)
+    }
+
+    /// Handler for `txpool_transactionsByHash`
+    async fn txpool_transactions_by_hash(
+        &self,
+        hashes: Vec<B256>,
+    ) -> Result<Vec<Option<TxpoolTransactionByHash>>> {
+        trace!(target: "rpc::eth", hashes = hashes.len(), "Serving txpool_transactionsByHash");
+        self.transactions_by_hash(hashes)
+    }
+
+    /// Handler for `txpool_blobGasRatio`
+    async fn txpool_blob_gas_ratio(&self) -> Result<TxpoolBlobGasRatio> {
+        trace!(target: "rpc::eth", "Serving txpool_blobGasRatio");
+        Ok(self.blob_gas_ratio())
+    }
+
+    /// Handler for `txpool_sample`
+    async fn txpool_sample(&self, n: usize, seed: u64) -> Result<Vec<Transaction>> {
+        trace!(target: "rpc::eth", n, seed, "Serving txpool_sample");
+        Ok(self.sample(n, seed))
+    }
+
+    /// Handler for `txpool_unlockOnBasefeeDrop`
+    async fn txpool_unlock_on_basefee_drop(&self, drop_pct: u8) -> Result<TxpoolBasefeeDropUnlock> {
+        trace!(target: "rpc::eth", drop_pct, "Serving txpool_unlockOnBasefeeDrop");
+        self.unlock_on_basefee_drop(drop_pct)
+    }
+
+    /// Handler for `txpool_priorityFeeDistribution`
+    async fn txpool_priority_fee_distribution(
+        &self,
+        bucket_bounds: Vec<U256>,
+    ) -> Result<TxpoolPriorityFeeDistribution> {
+        trace!(target: "rpc::eth", ?bucket_bounds, "Serving txpool_priorityFeeDistribution");
+        self.priority_fee_distribution(bucket_bounds)
+    }
+
+    /// Handler for `txpool_slotOccupant`
+    async fn txpool_slot_occupant(
+        &self,
+        sender: Address,
+        nonce: u64,
+    ) -> Result<Option<Transaction>> {
+        trace!(target: "rpc::eth", ?sender, nonce, "Serving txpool_slotOccupant");
+        // Looked up in the pool directly instead of the shared snapshot, so a replacement is
+        // visible right away
+        Ok(self.pool.get_transactions_by_sender_and_nonce(sender, nonce).map(|tx| {
+            reth_rpc_types_compat::transaction::from_recovered(tx.transaction.clone().into())
+        }))
+    }
+
+    /// Handler for `txpool_dependencyGraph`
+    async fn txpool_dependency_graph(&self, sender: Address) -> Result<TxpoolDependencyGraph> {
+        trace!(target: "rpc::eth", ?sender, "Serving txpool_dependencyGraph");
+        self.dependency_graph(sender)
+    }
+
+    /// Handler for `txpool_nonmonotonicFeeQueues`
+    async fn txpool_nonmonotonic_fee_queues(&self) -> Result<BTreeMap<Address, Vec<u64>>> {
+        trace!(target: "rpc::eth", "Serving txpool_nonmonotonicFeeQueues");
+        Ok(self.nonmonotonic_fee_queues())
+    }
+
+    /// Handler for `txpool_arrivalTimeline`
+    async fn txpool_arrival_timeline(
+        &self,
+        window_secs: u64,
+        bucket_secs: u64,
+    ) -> Result<Vec<u64>> {
+        trace!(target: "rpc::eth", window_secs, bucket_secs, "Serving txpool_arrivalTimeline");
+        self.arrival_timeline(Instant::now(), window_secs, bucket_secs)
+    }
+
+    /// Handler for `txpool_oversizedBlobTxs`
+    async fn txpool_oversized_blob_txs(&self) -> Result<Vec<TxpoolOversizedBlobTx>> {
+        trace!(target: "rpc::eth", "Serving txpool_oversizedBlobTxs");
+        Ok(self.oversized_blob_txs())
+    }
+
+    /// Handler for `txpool_byProfitability`
+    async fn txpool_by_profitability(&self, limit: usize) -> Result<Vec<TxpoolRankedTransaction>> {
+        trace!(target: "rpc::eth", limit, "Serving txpool_byProfitability");
+        Ok(self.by_profitability(limit))
     }
 }
 
-impl<Pool> std::fmt::Debug for TxPoolApi<Pool> {
+/// The default time a snapshot of all pool transactions is shared between requests.
+const DEFAULT_TXPOOL_CACHE_TTL: Duration = Duration::from_millis(200);
+
+/// The maximum number of buckets of a `txpool_arrivalTimeline` response.
+const MAX_ARRIVAL_TIMELINE_BUCKETS: u64 = 3600;
+
+/// The maximum number of transactions returned by `txpool_byProfitability`.
+const MAX_BY_PROFITABILITY_LEN: usize = 1024;
+
+/// The maximum number of addresses that can be looked up in `txpool_contentFromMany`.
+const MAX_CONTENT_FROM_MANY_LEN: usize = 1024;
+
+/// The maximum number of transactions returned by a `txpool_contentPaginated` page.
+const MAX_CONTENT_PAGE_SIZE: u64 = 1024;
+
+/// The maximum number of senders that can be excluded in `txpool_orderedExcluding`.
+const MAX_ORDERING_BLOCKLIST_LEN: usize = 1024;
+
+/// The maximum number of bucket bounds accepted by `txpool_priorityFeeDistribution`.
+const MAX_PRIORITY_FEE_BUCKET_BOUNDS: usize = 256;
+
+/// The maximum number of transactions returned by `txpool_sample`.
+const MAX_SAMPLE_LEN: usize = 1024;
+
+/// The maximum number of hashes that can be looked up in `txpool_transactionsByHash`.
+const MAX_TRANSACTIONS_BY_HASH_LEN: usize = 1024;
+
+/// The maximum number of affected transaction hashes returned by `txpool_simulateMinFee`.
+const MIN_FEE_SIMULATION_SAMPLE_SIZE: usize = 100;
+
+/// The number of transactions converted per task when building the `txpool_content` response.
+///
+/// Smaller sets of transactions are converted on the calling thread.
+const CONTENT_CHUNK_SIZE: usize = 512;
+
+/// The longest calldata, in bytes, of a transaction reported by `txpool_gasAnomalies`.
+///
+/// This fits a bare function selector, but no call arguments.
+const MAX_GAS_ANOMALY_INPUT_LEN: usize = 4;
+
+/// The maximum size of the bloom filter returned by `txpool_hashBloom`, 1 MiB.
+const MAX_HASH_BLOOM_BITS: usize = 8 * 1024 * 1024;
+
+/// The maximum number of hash functions used by the bloom filter returned by `txpool_hashBloom`.
+const MAX_HASH_BLOOM_HASHES: u64 = 32;
+
+/// Builds the bloom filter returned by `txpool_hashBloom`.
+///
+/// The number of hash functions is chosen to minimize the false-positive rate for the given number
+/// of hashes, `k = m / n * ln 2`.
+fn build_hash_bloom(hashes: &[TxHash], bits: usize) -> TxpoolHashBloom {
+    let hash_count = if hashes.is_empty() {
+        1
+    } else {
+        ((bits as f64 / hashes.len() as f64) * std::f64::consts::LN_2).round() as u64
+    }
+    .clamp(1, MAX_HASH_BLOOM_HASHES);
+
+    let mut bloom = vec![0u8; bits.div_ceil(8)];
+    for hash in hashes {
+        for index in hash_bloom_indices(hash, bits, hash_count) {
+            bloom[index / 8] |= 1 << (index % 8);
+        }
+    }
+
+    TxpoolHashBloom {
+        bloom: bloom.into(),
+        bits: bits as u64,
+        hash_count,
+        items: hashes.len() as u64,
+    }
+}
+
+/// Returns the bit indices of the given hash in a bloom filter of `bits` size, using double
+/// hashing over the first 16 bytes of the hash.
+fn hash_bloom_indices(
+    hash: &TxHash,
+    bits: usize,
+    hash_count: u64,
+) -> impl Iterator<Item = usize> + '_ {
+    let h1 = u64::from_be_bytes(hash[..8].try_into().expect("slice is 8 bytes"));
+    let h2 = u64::from_be_bytes(hash[8..16].try_into().expect("slice is 8 bytes"));
+    (0..hash_count).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % bits as u64) as usize)
+}
+
+/// Converts the transactions matching the filter and groups them by sender and nonce.
+///
+/// Large sets of transactions are converted in parallel chunks whose maps are merged afterwards.
+/// A sender and nonce identify a single pooled transaction, so the merged map is the same as the
+/// one built serially.
+fn content_by_sender<T: PoolTransaction>(
+    transactions: &[Arc<ValidPoolTransaction<T>>],
+    filter: &(impl Fn(&ValidPoolTransaction<T>) -> bool + Sync),
+) -> BTreeMap<Address, BTreeMap<String, Transaction>> {
+    let convert = |chunk: &[Arc<ValidPoolTransaction<T>>]| {
+        let mut content = BTreeMap::<_, BTreeMap<_, _>>::new();
+        for tx in chunk.iter().filter(|tx| filter(tx)) {
+            content.entry(tx.sender()).or_default().insert(
+                tx.nonce().to_string(),
+                reth_rpc_types_compat::transaction::from_recovered(tx.transaction.clone().into()),
+            );
+        }
+        content
+    };
+
+    if transactions.len() <= CONTENT_CHUNK_SIZE {
+        return convert(transactions)
+    }
+
+    transactions.par_chunks(CONTENT_CHUNK_SIZE).map(convert).reduce(
+        BTreeMap::new,
+        |mut acc, chunk| {
+            for (sender, transactions) in chunk {
+                acc.entry(sender).or_default().extend(transactions);
+            }
+            acc
+        },
+    )
+}
+
+/// Computes the `txpool_contentDigest` response, sorting the hashes first so that the digest does
+/// not depend on the order of the pool.
+fn content_digest(mut hashes: Vec<TxHash>) -> TxpoolContentDigest {
+    hashes.sort_unstable();
+    let digest = keccak256(hashes.iter().flat_map(|hash| hash.0).collect::<Vec<_>>());
+    TxpoolContentDigest { digest, count: hashes.len() as u64 }
+}
+
+impl<Provider, Pool: TransactionPool> std::fmt::Debug for TxPoolApi<Provider, Pool> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TxpoolApi").finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{BlobTransactionSidecar, TxKind};
+    use reth_provider::test_utils::NoopProvider;
+    use reth_transaction_pool::{
+        test_utils::{testing_pool, MockTransaction},
+        BlockInfo, TransactionOrigin, TransactionPoolExt,
+    };
+    use std::sync::OnceLock;
+
+    /// Returns a blocking task pool shared by all tests.
+    fn blocking_task_pool() -> BlockingTaskPool {
+        static POOL: OnceLock<BlockingTaskPool> = OnceLock::new();
+        POOL.get_or_init(|| BlockingTaskPool::build().unwrap()).clone()
+    }
+
+    fn bloom_contains(bloom: &TxpoolHashBloom, hash: &TxHash) -> bool {
+        hash_bloom_indices(hash, bloom.bits as usize, bloom.hash_count)
+            .all(|index| bloom.bloom[index / 8] & (1 << (index % 8)) != 0)
+    }
+
+    #[test]
+    fn hash_bloom_contains_all_hashes() {
+        let hashes = (0..1000).map(|_| TxHash::random()).collect::<Vec<_>>();
+        for bits in [1, 7, 100, 10_000] {
+            let bloom = build_hash_bloom(&hashes, bits);
+            assert_eq!(bloom.bloom.len(), bits.div_ceil(8));
+            assert_eq!(bloom.items, hashes.len() as u64);
+            assert!(hashes.iter().all(|hash| bloom_contains(&bloom, hash)));
+        }
+    }
+
+    #[test]
+    fn hash_bloom_empty() {
+        let bloom = build_hash_bloom(&[], 64);
+        assert_eq!(bloom.hash_count, 1);
+        assert!(bloom.bloom.iter().all(|byte| *byte == 0));
+    }
+
+    #[tokio::test]
+    async fn gas_weighted_avg_fee() {
+        let pool = testing_pool();
+        let api = TxPoolApi::new(NoopProvider::default(), pool.clone(), blocking_task_pool());
+        assert_eq!(api.gas_weighted_avg_fee(), U256::ZERO);
+
+        let small = MockTransaction::eip1559()
+            .with_gas_limit(21_000)
+            .with_max_fee(10)
+            .with_priority_fee(10);
+        let large = MockTransaction::eip1559()
+            .with_gas_limit(63_000)
+            .with_max_fee(30)
+            .with_priority_fee(30);
+        pool.add_transaction(TransactionOrigin::External, small).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, large).await.unwrap();
+
+        // (10 * 21_000 + 30 * 63_000) / 84_000, a simple mean would be 20
+        assert_eq!(api.gas_weighted_avg_fee(), U256::from(25));
+    }
+
+    #[tokio::test]
+    async fn content_paginated() {
+        let pool = testing_pool();
+        let api = TxPoolApi::new(NoopProvider::default(), pool.clone(), blocking_task_pool());
+
+        let tx = MockTransaction::eip1559();
+        for tx in [tx.clone(), tx.next(), tx.next().next()] {
+            pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        }
+
+        let first = api.content_paginated(0, 2).unwrap();
+        assert!(first.has_more);
+        let nonces = first.content.pending.values().flat_map(|txs| txs.keys()).collect::<Vec<_>>();
+        assert_eq!(nonces, vec!["0", "1"]);
+
+        let second = api.content_paginated(2, 2).unwrap();
+        assert!(!second.has_more);
+        let nonces = second.content.pending.values().flat_map(|txs| txs.keys()).collect::<Vec<_>>();
+        assert_eq!(nonces, vec!["2"]);
+
+        let past_end = api.content_paginated(10, 2).unwrap();
+        assert!(!past_end.has_more);
+        assert!(past_end.content.pending.is_empty() && past_end.content.queued.is_empty());
+
+        assert!(api.content_paginated(0, MAX_CONTENT_PAGE_SIZE).is_ok());
+        assert!(api.content_paginated(0, MAX_CONTENT_PAGE_SIZE + 1).is_err());
+        assert!(api.txpool_content_paginated(0, u64::MAX).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn content_from_many() {
+        let pool = testing_pool();
+        let api = TxPoolApi::new(NoopProvider::default(), pool.clone(), blocking_task_pool());
+
+        let tx = MockTransaction::eip1559();
+        pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+
+        let absent = Address::random();
+        let content = api.txpool_content_from_many(vec![tx.get_sender(), absent]).await.unwrap();
+        assert_eq!(content[&tx.get_sender()].pending.len(), 1);
+        assert!(content[&absent].pending.is_empty() && content[&absent].queued.is_empty());
+
+        let too_many = vec![Address::ZERO; MAX_CONTENT_FROM_MANY_LEN + 1];
+        assert!(api.txpool_content_from_many(too_many).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn count_matches_status() {
+        let pool = testing_pool();
+        let api = TxPoolApi::new(NoopProvider::default(), pool.clone(), blocking_task_pool());
+
+        let tx = MockTransaction::eip1559();
+        // a nonce gap queues the second transaction
+        for tx in [tx.clone(), tx.skip(1)] {
+            pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        }
+
+        let count = api.txpool_count().await.unwrap();
+        assert_eq!(count, api.txpool_status().await.unwrap());
+        assert_eq!(count, TxpoolStatus { pending: 1, queued: 1 });
+    }
+
+    #[test]
+    fn content_digest_is_order_independent() {
+        let mut hashes = (0..100).map(|_| TxHash::random()).collect::<Vec<_>>();
+        let digest = content_digest(hashes.clone());
+        assert_eq!(digest.count, 100);
+
+        hashes.reverse();
+        assert_eq!(content_digest(hashes.clone()), digest);
+        hashes.swap(0, 50);
+        assert_eq!(content_digest(hashes.clone()), digest);
+
+        hashes.pop();
+        assert_ne!(content_digest(hashes).digest, digest.digest);
+    }
+
+    #[tokio::test]
+    async fn all_transactions_cache() {
+        let pool = testing_pool();
+        let api = TxPoolApi::new(NoopProvider::default(), pool.clone(), blocking_task_pool())
+            .with_cache_ttl(Duration::from_secs(3600));
+        assert_eq!(api.txpool_status().await.unwrap().pending, 0);
+
+        pool.add_transaction(TransactionOrigin::External, MockTransaction::eip1559())
+            .await
+            .unwrap();
+
+        // the snapshot is served until the TTL expires
+        assert_eq!(api.txpool_status().await.unwrap().pending, 0);
+        let api = api.with_cache_ttl(Duration::ZERO);
+        assert_eq!(api.txpool_status().await.unwrap().pending, 1);
+    }
+
+    #[tokio::test]
+    async fn first_eligible_block_of_pending_and_queued() {
+        let pool = testing_pool();
+        let pending = MockTransaction::eip1559();
+        let queued = pending.skip(1);
+        pool.add_transaction(TransactionOrigin::External, pending.clone()).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, queued.clone()).await.unwrap();
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool.clone(), blocking_task_pool());
+        let entries = api.first_eligible_blocks();
+        let next_block = pool.block_info().last_seen_block_number + 1;
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].hash, pending.get_hash());
+        assert_eq!(entries[0].first_eligible_block, Some(next_block));
+        assert_eq!(entries[1].hash, queued.get_hash());
+        assert_eq!(entries[1].first_eligible_block, None);
+    }
+
+    #[tokio::test]
+    async fn content_ranked_by_effective_tip() {
+        let pool = testing_pool();
+        let low = MockTransaction::eip1559().with_max_fee(100).with_priority_fee(1);
+        let high = MockTransaction::eip1559().with_max_fee(100).with_priority_fee(5);
+        let queued = high.skip(1);
+        for tx in [low.clone(), high.clone(), queued.clone()] {
+            pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        }
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let content = api.content_ranked();
+
+        let ranked = content.pending.iter().map(|tx| tx.sender).collect::<Vec<_>>();
+        assert_eq!(ranked, vec![high.get_sender(), low.get_sender()]);
+        assert!(content.pending[0].effective_tip > content.pending[1].effective_tip);
+        assert!(content.queued[&queued.get_sender()].contains_key(&queued.get_nonce().to_string()));
+    }
+
+    #[tokio::test]
+    async fn inspect_reports_effective_gas_price() {
+        let pool = testing_pool();
+        let base_fee = pool.block_info().pending_basefee as u128;
+        let dynamic = MockTransaction::eip1559().with_max_fee(base_fee + 100).with_priority_fee(3);
+        let legacy = MockTransaction::legacy().with_gas_price(base_fee + 50);
+        for tx in [dynamic.clone(), legacy.clone()] {
+            pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        }
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let inspect = api.txpool_inspect().await.unwrap();
+        let gas_price = |tx: &MockTransaction| {
+            inspect.pending[&tx.sender()][&tx.get_nonce().to_string()].gas_price
+        };
+
+        assert_eq!(gas_price(&dynamic), base_fee + 3);
+        assert_eq!(gas_price(&legacy), base_fee + 50);
+    }
+
+    #[tokio::test]
+    async fn effective_gas_price_of_pooled_transaction() {
+        let pool = testing_pool();
+        let base_fee = pool.block_info().pending_basefee as u128;
+        let capped = MockTransaction::eip1559().with_max_fee(base_fee + 2).with_priority_fee(10);
+        pool.add_transaction(TransactionOrigin::External, capped.clone()).await.unwrap();
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        assert_eq!(api.effective_gas_price(capped.get_hash()).unwrap(), U256::from(base_fee + 2));
+        assert!(api.effective_gas_price(B256::random()).is_err());
+    }
+
+    #[tokio::test]
+    async fn locals_only_include_local_transactions() {
+        let pool = testing_pool();
+        let local = MockTransaction::eip1559();
+        let external = MockTransaction::eip1559();
+        pool.add_transaction(TransactionOrigin::Local, local.clone()).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, external.clone()).await.unwrap();
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let locals = api.txpool_locals().await.unwrap();
+
+        assert_eq!(locals.pending.len(), 1);
+        assert!(locals.pending.contains_key(&local.get_sender()));
+        assert!(locals.queued.is_empty());
+    }
+
+    #[tokio::test]
+    async fn tx_status_reports_queued_reasons() {
+        let pool = testing_pool();
+        let pending = MockTransaction::eip1559();
+        let gapped = pending.skip(1);
+        pool.add_transaction(TransactionOrigin::External, pending.clone()).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, gapped.clone()).await.unwrap();
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        assert_eq!(api.tx_status(pending.get_hash()), TxpoolTxStatus::Pending);
+        assert_eq!(
+            api.tx_status(gapped.get_hash()),
+            TxpoolTxStatus::Queued { reasons: vec![TxpoolQueuedReason::NonceGap] }
+        );
+        assert_eq!(api.tx_status(B256::random()), TxpoolTxStatus::NotFound);
+    }
+
+    #[tokio::test]
+    async fn churn_stats_count_new_and_replaced() {
+        let pool = testing_pool();
+        let tx = MockTransaction::eip1559().with_max_fee(100).with_priority_fee(10);
+        let replacement = tx.clone().rng_hash().with_max_fee(200).with_priority_fee(20);
+        pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, replacement).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, MockTransaction::eip1559())
+            .await
+            .unwrap();
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let stats = api.churn_stats(60).unwrap();
+        assert_eq!((stats.new_count, stats.replaced_count, stats.removed_count), (2, 1, 0));
+        assert!(api.churn_stats(MAX_CHURN_WINDOW.as_secs() + 1).is_err());
+    }
+
+    #[tokio::test]
+    async fn content_by_sender_parallel_matches_serial() {
+        let pool = testing_pool();
+        let senders = (0..8).map(|_| MockTransaction::eip1559()).collect::<Vec<_>>();
+        let transactions = senders
+            .iter()
+            .flat_map(|first| {
+                std::iter::successors(Some(first.clone()), |tx| Some(tx.next())).take(16)
+            })
+            .collect::<Vec<_>>();
+        for tx in transactions {
+            pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        }
+
+        let pending = pool.pending_transactions();
+        assert!(pending.len() > 1);
+        let serial = content_by_sender(&pending, &|_| true);
+
+        // repeat the pool content until it is split into several chunks
+        let repeated =
+            pending.iter().cycle().take(CONTENT_CHUNK_SIZE * 3).cloned().collect::<Vec<_>>();
+        assert_eq!(content_by_sender(&repeated, &|_| true), serial);
+    }
+
+    #[tokio::test]
+    async fn gas_anomalies_sorted_by_gas_limit() {
+        let pool = testing_pool();
+        let small = MockTransaction::eip1559().with_gas_limit(100_000);
+        let large = MockTransaction::eip1559().with_gas_limit(200_000);
+        let with_calldata =
+            MockTransaction::eip1559().with_gas_limit(300_000).with_input(vec![1; 36].into());
+        let below_min = MockTransaction::eip1559().with_gas_limit(21_000);
+        for tx in [&small, &large, &with_calldata, &below_min] {
+            pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+        }
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let anomalies = api.txpool_gas_anomalies(50_000).await.unwrap();
+
+        assert_eq!(
+            anomalies.iter().map(|anomaly| anomaly.hash).collect::<Vec<_>>(),
+            vec![large.get_hash(), small.get_hash()]
+        );
+        assert_eq!(anomalies[0].gas_limit, 200_000);
+        assert_eq!(anomalies[0].input_len, 0);
+    }
+
+    #[tokio::test]
+    async fn self_sends_only_include_transactions_to_the_sender() {
+        let pool = testing_pool();
+        let mut self_send = MockTransaction::eip1559();
+        if let MockTransaction::Eip1559 { sender, to, .. } = &mut self_send {
+            *to = TxKind::Call(*sender);
+        }
+        let transfer = MockTransaction::eip1559();
+        pool.add_transaction(TransactionOrigin::External, self_send.clone()).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, transfer).await.unwrap();
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let self_sends = api.txpool_self_sends().await.unwrap();
+
+        assert_eq!(self_sends.pending.len(), 1);
+        assert!(self_sends.pending.contains_key(&self_send.get_sender()));
+        assert!(self_sends.queued.is_empty());
+    }
+
+    #[tokio::test]
+    async fn estimate_wait_counts_higher_paying_transactions() {
+        let pool = testing_pool();
+        for fee in [10, 20, 30] {
+            let tx = MockTransaction::eip1559()
+                .with_gas_limit(21_000)
+                .with_max_fee(fee)
+                .with_priority_fee(fee);
+            pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        }
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        assert_eq!(
+            api.txpool_estimate_wait(U256::from(15), 0.5).await.unwrap(),
+            TxpoolWaitEstimate {
+                transactions_ahead: 2,
+                gas_ahead: 42_000,
+                blocks: 1,
+                wait_secs: 2
+            }
+        );
+        assert_eq!(
+            api.txpool_estimate_wait(U256::from(30), 0.5).await.unwrap().transactions_ahead,
+            0
+        );
+        assert!(api.txpool_estimate_wait(U256::from(15), 0.0).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn transactions_by_hash() {
+        let pool = testing_pool();
+        let pending = MockTransaction::eip1559();
+        let queued = MockTransaction::eip1559().skip(1);
+        pool.add_transaction(TransactionOrigin::External, pending.clone()).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, queued.clone()).await.unwrap();
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let missing = B256::random();
+        let found = api
+            .txpool_transactions_by_hash(vec![queued.get_hash(), missing, pending.get_hash()])
+            .await
+            .unwrap();
+
+        let sub_pools = found
+            .iter()
+            .map(|entry| entry.as_ref().map(|entry| entry.sub_pool))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sub_pools,
+            vec![Some(TxpoolSubPool::Queued), None, Some(TxpoolSubPool::Pending)]
+        );
+        assert_eq!(found[2].as_ref().unwrap().transaction.hash, pending.get_hash());
+
+        let too_many = vec![missing; MAX_TRANSACTIONS_BY_HASH_LEN + 1];
+        assert!(api.txpool_transactions_by_hash(too_many).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn blob_gas_ratio_of_pending_transactions() {
+        let pool = testing_pool();
+        let api = TxPoolApi::new(NoopProvider::default(), pool.clone(), blocking_task_pool());
+        assert_eq!(api.txpool_blob_gas_ratio().await.unwrap(), TxpoolBlobGasRatio::default());
+
+        let sidecar = BlobTransactionSidecar {
+            blobs: vec![Default::default(); 2],
+            commitments: vec![Default::default(); 2],
+            proofs: vec![Default::default(); 2],
+        };
+        let blob = MockTransaction::eip4844_with_sidecar(sidecar).with_gas_limit(100_000);
+        let plain = MockTransaction::eip1559().with_gas_limit(300_000);
+        pool.add_transaction(TransactionOrigin::External, blob).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, plain).await.unwrap();
+
+        let ratio = api.txpool_blob_gas_ratio().await.unwrap();
+        assert_eq!(ratio.total_blob_gas, 2 * DATA_GAS_PER_BLOB);
+        assert_eq!(ratio.total_execution_gas, 400_000);
+        assert_eq!(ratio.blob_ratio, (2 * DATA_GAS_PER_BLOB) as f64 / 400_000.0);
+    }
+
+    #[tokio::test]
+    async fn sample_is_deterministic_without_replacement() {
+        let pool = testing_pool();
+        let transactions = (0..10).map(|_| MockTransaction::eip1559()).collect::<Vec<_>>();
+        for tx in &transactions {
+            pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+        }
+        let queued = MockTransaction::eip1559().skip(1);
+        pool.add_transaction(TransactionOrigin::External, queued.clone()).await.unwrap();
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let hashes = |sample: Vec<Transaction>| sample.into_iter().map(|tx| tx.hash).collect();
+
+        let sample: Vec<_> = hashes(api.txpool_sample(4, 42).await.unwrap());
+        assert_eq!(sample.len(), 4);
+        assert_eq!(sample.iter().collect::<HashSet<_>>().len(), 4);
+        assert_eq!(hashes(api.txpool_sample(4, 42).await.unwrap()), sample);
+
+        // the sample is capped at the number of pending transactions
+        let all: Vec<_> = hashes(api.txpool_sample(100, 7).await.unwrap());
+        assert_eq!(all.len(), transactions.len());
+        assert!(!all.contains(&queued.get_hash()));
+    }
+
+    #[tokio::test]
+    async fn unlock_on_basefee_drop() {
+        let pool = testing_pool();
+        pool.set_block_info(BlockInfo { pending_basefee: 1000, ..pool.block_info() });
+
+        let pending = MockTransaction::eip1559().with_max_fee(2000).with_priority_fee(1);
+        let unlocked = MockTransaction::eip1559().with_max_fee(600).with_priority_fee(1);
+        let still_parked = MockTransaction::eip1559().with_max_fee(400).with_priority_fee(1);
+        for tx in [&pending, &unlocked, &still_parked] {
+            pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+        }
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let result = api.txpool_unlock_on_basefee_drop(50).await.unwrap();
+        assert_eq!(result.base_fee, 1000);
+        assert_eq!(result.hypothetical_base_fee, 500);
+        assert_eq!(
+            result.transactions.iter().map(|tx| tx.hash).collect::<Vec<_>>(),
+            vec![unlocked.get_hash()]
+        );
+
+        assert!(api.txpool_unlock_on_basefee_drop(0).await.is_err());
+        assert!(api.txpool_unlock_on_basefee_drop(101).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn priority_fee_distribution() {
+        let pool = testing_pool();
+        for tx in [
+            MockTransaction::eip1559().with_max_fee(100).with_priority_fee(5),
+            MockTransaction::eip1559().with_max_fee(100).with_priority_fee(10),
+            MockTransaction::eip1559().with_max_fee(100).with_priority_fee(15),
+            MockTransaction::eip1559().with_max_fee(100).with_priority_fee(50),
+            MockTransaction::legacy().with_gas_price(100),
+        ] {
+            pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        }
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let distribution = api
+            .txpool_priority_fee_distribution(vec![U256::from(10), U256::from(20)])
+            .await
+            .unwrap();
+        assert_eq!(
+            distribution.buckets.iter().map(|bucket| bucket.count).collect::<Vec<_>>(),
+            vec![1, 2, 1]
+        );
+        assert_eq!(distribution.buckets[0].min, U256::ZERO);
+        assert_eq!(distribution.buckets[2].max, None);
+        assert_eq!(distribution.legacy, 1);
+
+        let unsorted = vec![U256::from(20), U256::from(10)];
+        assert!(api.txpool_priority_fee_distribution(unsorted).await.is_err());
+        let duplicate = vec![U256::from(10), U256::from(10)];
+        assert!(api.txpool_priority_fee_distribution(duplicate).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn slot_occupant_reflects_replacement() {
+        let pool = testing_pool();
+        let tx = MockTransaction::eip1559().with_max_fee(100).with_priority_fee(10);
+        pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool.clone(), blocking_task_pool());
+        let occupant = api.txpool_slot_occupant(tx.sender(), 0).await.unwrap();
+        assert_eq!(occupant.map(|occupant| occupant.hash), Some(tx.get_hash()));
+
+        let replacement = tx.clone().rng_hash().with_max_fee(200).with_priority_fee(20);
+        pool.add_transaction(TransactionOrigin::External, replacement.clone()).await.unwrap();
+        let occupant = api.txpool_slot_occupant(tx.sender(), 0).await.unwrap();
+        assert_eq!(occupant.map(|occupant| occupant.hash), Some(replacement.get_hash()));
+
+        assert!(api.txpool_slot_occupant(tx.sender(), 1).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn dependency_graph_links_consecutive_nonces() {
+        let pool = testing_pool();
+        let first = MockTransaction::eip1559();
+        let second = first.next();
+        let gapped = second.skip(1);
+        for tx in [&first, &second, &gapped] {
+            pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+        }
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let graph = api.txpool_dependency_graph(first.sender()).await.unwrap();
+        assert_eq!(graph.on_chain_nonce, 0);
+        assert_eq!(graph.first_includable, Some(first.get_hash()));
+        assert_eq!(
+            graph.transactions,
+            vec![
+                TxpoolDependencyNode {
+                    hash: first.get_hash(),
+                    nonce: 0,
+                    depends_on: None,
+                    gapped: false
+                },
+                TxpoolDependencyNode {
+                    hash: second.get_hash(),
+                    nonce: 1,
+                    depends_on: Some(first.get_hash()),
+                    gapped: false
+                },
+                TxpoolDependencyNode {
+                    hash: gapped.get_hash(),
+                    nonce: 3,
+                    depends_on: None,
+                    gapped: true
+                },
+            ]
+        );
+
+        let unknown = api.txpool_dependency_graph(Address::random()).await.unwrap();
+        assert_eq!(unknown.first_includable, None);
+        assert!(unknown.transactions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn nonmonotonic_fee_queues_report_fee_decreases() {
+        let pool = testing_pool();
+        let first = MockTransaction::eip1559().with_max_fee(300).with_priority_fee(1);
+        let second = first.next().with_max_fee(200);
+        let third = second.next().with_max_fee(250);
+        let monotonic = MockTransaction::eip1559().with_max_fee(100).with_priority_fee(1);
+        for tx in [&first, &second, &third, &monotonic, &monotonic.next()] {
+            pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+        }
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let queues = api.txpool_nonmonotonic_fee_queues().await.unwrap();
+        assert_eq!(queues, BTreeMap::from([(first.sender(), vec![1])]));
+    }
+
+    #[tokio::test]
+    async fn arrival_timeline_buckets_by_age() {
+        let pool = testing_pool();
+        pool.add_transaction(TransactionOrigin::External, MockTransaction::eip1559())
+            .await
+            .unwrap();
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let now = Instant::now() + Duration::from_secs(5);
+        assert_eq!(api.arrival_timeline(now, 10, 2).unwrap(), vec![0, 0, 1, 0, 0]);
+        assert_eq!(api.arrival_timeline(now, 4, 2).unwrap(), vec![0, 0]);
+
+        assert!(api.arrival_timeline(now, 10, 3).is_err());
+        assert!(api.arrival_timeline(now, 10, 0).is_err());
+        assert!(api.arrival_timeline(now, 3601, 1).is_err());
+    }
+
+    #[tokio::test]
+    async fn oversized_blob_txs() {
+        let pool = testing_pool();
+        let api = TxPoolApi::new(NoopProvider::default(), pool.clone(), blocking_task_pool());
+
+        let sidecar = |blobs| BlobTransactionSidecar {
+            blobs: vec![Default::default(); blobs],
+            commitments: vec![Default::default(); blobs],
+            proofs: vec![Default::default(); blobs],
+        };
+        let allowed = MockTransaction::eip4844_with_sidecar(sidecar(MAX_BLOBS_PER_BLOCK));
+        let oversized = MockTransaction::eip4844_with_sidecar(sidecar(MAX_BLOBS_PER_BLOCK + 1));
+        for tx in [&allowed, &oversized] {
+            pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+        }
+
+        let oversized_txs = api.txpool_oversized_blob_txs().await.unwrap();
+        assert_eq!(
+            oversized_txs,
+            vec![TxpoolOversizedBlobTx {
+                hash: oversized.get_hash(),
+                sender: oversized.sender(),
+                nonce: 0,
+                blob_count: MAX_BLOBS_PER_BLOCK as u64 + 1,
+                max_blob_count: MAX_BLOBS_PER_BLOCK as u64,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn by_profitability_orders_by_tip_then_hash() {
+        let pool = testing_pool();
+        let base_fee = pool.block_info().pending_basefee as u128;
+        let high = MockTransaction::eip1559().with_max_fee(base_fee + 100).with_priority_fee(10);
+        let low = MockTransaction::eip1559().with_max_fee(base_fee + 100).with_priority_fee(2);
+        let tied = MockTransaction::eip1559().with_max_fee(base_fee + 100).with_priority_fee(2);
+        for tx in [&high, &low, &tied] {
+            pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+        }
+
+        let api = TxPoolApi::new(NoopProvider::default(), pool, blocking_task_pool());
+        let ranked = api.txpool_by_profitability(10).await.unwrap();
+        let (first_tied, second_tied) = if low.get_hash() < tied.get_hash() {
+            (low.get_hash(), tied.get_hash())
+        } else {
+            (tied.get_hash(), low.get_hash())
+        };
+        assert_eq!(
+            ranked.iter().map(|tx| tx.transaction.hash).collect::<Vec<_>>(),
+            vec![high.get_hash(), first_tied, second_tied]
+        );
+        assert_eq!(ranked.iter().map(|tx| tx.effective_tip).collect::<Vec<_>>(), vec![10, 2, 2]);
+
+        assert_eq!(api.txpool_by_profitability(1).await.unwrap().len(), 1);
+    }
+}
@@ -13,6 +13,9 @@ use reth_stages_api::{
 use std::fmt::Debug;
 use tracing::info;
 
+/// The source tag of prune checkpoints saved by [`IndexAccountHistoryStage`].
+const PRUNE_CHECKPOINT_SOURCE: &str = "index-account-history-stage";
+
 /// Stage is indexing history the account changesets generated in
 /// [`ExecutionStage`][crate::stages::ExecutionStage]. For more information
 /// on index sharding take a look at [`tables::AccountsHistory`]
@@ -74,13 +77,14 @@ impl<DB: Database> Stage<DB> for IndexAccountHistoryStage {
                 // Save prune checkpoint only if we don't have one already.
                 // Otherwise, pruner may skip the unpruned range of blocks.
                 if provider.get_prune_checkpoint(PruneSegment::AccountHistory)?.is_none() {
-                    provider.save_prune_checkpoint(
+                    provider.save_prune_checkpoint_tagged(
                         PruneSegment::AccountHistory,
                         PruneCheckpoint {
                             block_number: Some(target_prunable_block),
                             tx_number: None,
                             prune_mode,
                         },
+                        PRUNE_CHECKPOINT_SOURCE,
                     )?;
                 }
             }
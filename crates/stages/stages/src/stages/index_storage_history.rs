@@ -16,6 +16,9 @@ use reth_stages_api::{ExecInput, ExecOutput, Stage, StageError, UnwindInput, Unw
 use std::fmt::Debug;
 use tracing::info;
 
+/// The source tag of prune checkpoints saved by [`IndexStorageHistoryStage`].
+const PRUNE_CHECKPOINT_SOURCE: &str = "index-storage-history-stage";
+
 /// Stage is indexing history the account changesets generated in
 /// [`ExecutionStage`][crate::stages::ExecutionStage]. For more information
 /// on index sharding take a look at [`tables::StoragesHistory`].
@@ -77,13 +80,14 @@ impl<DB: Database> Stage<DB> for IndexStorageHistoryStage {
                 // Save prune checkpoint only if we don't have one already.
                 // Otherwise, pruner may skip the unpruned range of blocks.
                 if provider.get_prune_checkpoint(PruneSegment::StorageHistory)?.is_none() {
-                    provider.save_prune_checkpoint(
+                    provider.save_prune_checkpoint_tagged(
                         PruneSegment::StorageHistory,
                         PruneCheckpoint {
                             block_number: Some(target_prunable_block),
                             tx_number: None,
                             prune_mode,
                         },
+                        PRUNE_CHECKPOINT_SOURCE,
                     )?;
                 }
             }
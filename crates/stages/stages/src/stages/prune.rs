@@ -8,6 +8,9 @@ use reth_stages_api::{
 };
 use tracing::info;
 
+/// The source tag of prune checkpoints rewound by [`PruneStage::unwind`].
+const UNWIND_CHECKPOINT_SOURCE: &str = "prune-stage-unwind";
+
 /// The prune stage that runs the pruner with the provided prune modes.
 ///
 /// There are two main reasons to have this stage when running a full node:
@@ -92,11 +95,11 @@ impl<DB: Database> Stage<DB> for PruneStage {
     ) -> Result<UnwindOutput, StageError> {
         // We cannot recover the data that was pruned in `execute`, so we just update the
         // checkpoints.
-        let prune_checkpoints = provider.get_prune_checkpoints()?;
-        for (segment, mut checkpoint) in prune_checkpoints {
+        let mut prune_checkpoints = provider.get_prune_checkpoints()?;
+        for (_, checkpoint) in &mut prune_checkpoints {
             checkpoint.block_number = Some(input.unwind_to);
-            provider.save_prune_checkpoint(segment, checkpoint)?;
         }
+        provider.save_prune_checkpoints(&prune_checkpoints, UNWIND_CHECKPOINT_SOURCE)?;
         Ok(UnwindOutput { checkpoint: StageCheckpoint::new(input.unwind_to) })
     }
 }
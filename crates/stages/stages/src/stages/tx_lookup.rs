@@ -20,6 +20,9 @@ use reth_stages_api::{
 use reth_storage_errors::provider::ProviderError;
 use tracing::*;
 
+/// The source tag of prune checkpoints saved by [`TransactionLookupStage`].
+const PRUNE_CHECKPOINT_SOURCE: &str = "tx-lookup-stage";
+
 /// The transaction lookup stage.
 ///
 /// This stage walks over existing transactions, and sets the transaction hash of each transaction
@@ -89,13 +92,14 @@ impl<DB: Database> Stage<DB> for TransactionLookupStage {
                         .ok_or(ProviderError::BlockBodyIndicesNotFound(target_prunable_block))?
                         .last_tx_num();
 
-                    provider.save_prune_checkpoint(
+                    provider.save_prune_checkpoint_tagged(
                         PruneSegment::TransactionLookup,
                         PruneCheckpoint {
                             block_number: Some(target_prunable_block),
                             tx_number: Some(target_prunable_tx_number),
                             prune_mode,
                         },
+                        PRUNE_CHECKPOINT_SOURCE,
                     )?;
                 }
             }
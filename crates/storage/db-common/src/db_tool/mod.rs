@@ -3,7 +3,10 @@
 use boyer_moore_magiclen::BMByte;
 use eyre::Result;
 use reth_chainspec::ChainSpec;
-use reth_db::{RawTable, TableRawRow};
+use reth_db::{
+    mdbx::{tx::Tx, RO},
+    RawTable, TableRawRow, TableViewer, Tables,
+};
 use reth_db_api::{
     cursor::{DbCursorRO, DbDupCursorRO},
     database::Database,
@@ -129,6 +132,25 @@ impl<DB: Database> DbTool<DB> {
             .map_err(|e| eyre::eyre!(e))
     }
 
+    /// Returns the number of entries in the table.
+    pub fn table_entries<T: Table>(&self) -> Result<usize> {
+        self.provider_factory.db_ref().view(|tx| tx.entries::<T>())?.map_err(|e| eyre::eyre!(e))
+    }
+
+    /// Returns the names and entry counts of all tables that have entries, ordered by descending
+    /// number of entries.
+    pub fn non_empty_tables(&self) -> Result<Vec<(&'static str, usize)>> {
+        let mut tables = Vec::new();
+        for table in Tables::ALL {
+            let entries = table.view(&EntriesViewer(self))?;
+            if entries > 0 {
+                tables.push((table.name(), entries));
+            }
+        }
+        tables.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        Ok(tables)
+    }
+
     /// Drops the database and the static files at the given path.
     pub fn drop(
         &self,
@@ -154,6 +176,30 @@ impl<DB: Database> DbTool<DB> {
     }
 }
 
+/// Counts the entries of a table.
+struct EntriesViewer<'a, DB: Database>(&'a DbTool<DB>);
+
+impl<DB: Database> TableViewer<usize> for EntriesViewer<'_, DB> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<usize, Self::Error> {
+        self.0.table_entries::<T>()
+    }
+}
+
+impl<DB: Database<TX = Tx<RO>>> DbTool<DB> {
+    /// Returns the size of the table in bytes, computed from its number of branch, leaf and
+    /// overflow pages like `reth db stats` does.
+    pub fn table_size_bytes<T: Table>(&self) -> Result<usize> {
+        self.provider_factory.db_ref().view(|tx| {
+            let table_db = tx.inner.open_db(Some(T::NAME))?;
+            let stats = tx.inner.db_stat(&table_db)?;
+            let num_pages = stats.leaf_pages() + stats.branch_pages() + stats.overflow_pages();
+            Ok::<_, eyre::Report>(stats.page_size() as usize * num_pages)
+        })?
+    }
+}
+
 /// Filters the results coming from the database.
 #[derive(Debug)]
 pub struct ListFilter {
@@ -187,3 +233,57 @@ impl ListFilter {
         self.len = len;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_chainspec::MAINNET;
+    use reth_db::tables;
+    use reth_primitives::B256;
+    use reth_provider::test_utils::create_test_provider_factory_with_chain_spec;
+
+    #[test]
+    fn table_entries_and_size() {
+        let provider_factory = create_test_provider_factory_with_chain_spec(MAINNET.clone());
+        let tool = DbTool::new(provider_factory.clone()).unwrap();
+        assert_eq!(tool.table_entries::<tables::HeaderNumbers>().unwrap(), 0);
+        assert_eq!(tool.table_size_bytes::<tables::HeaderNumbers>().unwrap(), 0);
+
+        provider_factory
+            .db_ref()
+            .update(|tx| {
+                for number in 0..50 {
+                    tx.put::<tables::HeaderNumbers>(B256::with_last_byte(number as u8), number)?;
+                }
+                Ok::<_, DatabaseError>(())
+            })
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(tool.table_entries::<tables::HeaderNumbers>().unwrap(), 50);
+        // a single leaf page holds all entries
+        let page_size = provider_factory.db_ref().db().stat().unwrap().page_size();
+        assert_eq!(tool.table_size_bytes::<tables::HeaderNumbers>().unwrap(), page_size as usize);
+    }
+
+    #[test]
+    fn non_empty_tables_by_descending_entries() {
+        let provider_factory = create_test_provider_factory_with_chain_spec(MAINNET.clone());
+        provider_factory
+            .db_ref()
+            .update(|tx| {
+                for number in 0..3 {
+                    tx.put::<tables::HeaderNumbers>(B256::with_last_byte(number as u8), number)?;
+                }
+                tx.put::<tables::CanonicalHeaders>(0, B256::ZERO)
+            })
+            .unwrap()
+            .unwrap();
+
+        let tool = DbTool::new(provider_factory).unwrap();
+        assert_eq!(
+            tool.non_empty_tables().unwrap(),
+            vec![(tables::HeaderNumbers::NAME, 3), (tables::CanonicalHeaders::NAME, 1)]
+        );
+    }
+}
@@ -5,7 +5,9 @@ use reth_chainspec::ChainSpec;
 use reth_codecs::Compact;
 use reth_config::config::EtlConfig;
 use reth_db::tables;
-use reth_db_api::{database::Database, transaction::DbTxMut, DatabaseError};
+use reth_db_api::{
+    database::Database, models::BlockNumberAddress, transaction::DbTxMut, DatabaseError,
+};
 use reth_etl::Collector;
 use reth_primitives::{
     Account, Address, Bytecode, Receipts, StaticFileSegment, StorageEntry, B256, U256,
@@ -139,11 +141,18 @@ pub fn init_genesis<DB: Database>(factory: ProviderFactory<DB>) -> Result<B256,
 }
 
 /// Inserts the genesis state into the database.
+///
+/// The plain state is updated in place and the genesis changesets are replaced, so inserting the
+/// same state again, e.g. after an interrupted drop, leaves the database unchanged.
 pub fn insert_genesis_state<'a, 'b, DB: Database>(
     provider: &DatabaseProviderRW<DB>,
     capacity: usize,
     alloc: impl Iterator<Item = (&'a Address, &'b GenesisAccount)>,
 ) -> ProviderResult<()> {
+    // Changesets are appended, so drop the ones a previous insertion left behind
+    provider.remove::<tables::AccountChangeSets>(0..=0)?;
+    provider.remove::<tables::StorageChangeSets>(BlockNumberAddress::range(0..=0))?;
+
     insert_state::<DB>(provider, capacity, alloc, 0)
 }
 
@@ -262,13 +271,32 @@ pub fn insert_history<'a, 'b, DB: Database>(
     provider: &DatabaseProviderRW<DB>,
     alloc: impl Iterator<Item = (&'a Address, &'b GenesisAccount)> + Clone,
     block: u64,
+) -> ProviderResult<()> {
+    insert_account_history::<DB>(provider, alloc.clone(), block)?;
+    insert_storage_history::<DB>(provider, alloc, block)
+}
+
+/// Inserts account history indices for genesis accounts.
+pub fn insert_account_history<'a, 'b, DB: Database>(
+    provider: &DatabaseProviderRW<DB>,
+    alloc: impl Iterator<Item = (&'a Address, &'b GenesisAccount)>,
+    block: u64,
 ) -> ProviderResult<()> {
     let account_transitions =
-        alloc.clone().map(|(addr, _)| (*addr, vec![block])).collect::<BTreeMap<_, _>>();
+        alloc.map(|(addr, _)| (*addr, vec![block])).collect::<BTreeMap<_, _>>();
     provider.insert_account_history_index(account_transitions)?;
 
     trace!(target: "reth::cli", "Inserted account history");
 
+    Ok(())
+}
+
+/// Inserts storage history indices for the storage of genesis accounts.
+pub fn insert_storage_history<'a, 'b, DB: Database>(
+    provider: &DatabaseProviderRW<DB>,
+    alloc: impl Iterator<Item = (&'a Address, &'b GenesisAccount)>,
+    block: u64,
+) -> ProviderResult<()> {
     let storage_transitions = alloc
         .filter_map(|(addr, account)| account.storage.as_ref().map(|storage| (addr, storage)))
         .flat_map(|(addr, storage)| storage.iter().map(|(key, _)| ((*addr, *key), vec![block])))
@@ -281,6 +309,9 @@ pub fn insert_history<'a, 'b, DB: Database>(
 }
 
 /// Inserts header for the genesis state.
+///
+/// The header is only appended to the static files if they don't have it yet, so inserting it again
+/// leaves the database unchanged.
 pub fn insert_genesis_header<DB: Database>(
     provider: &DatabaseProviderRW<DB>,
     static_file_provider: &StaticFileProvider,
@@ -654,4 +685,67 @@ mod tests {
             )],
         );
     }
+
+    #[test]
+    fn insert_genesis_twice_is_noop() {
+        let address_with_code = Address::with_last_byte(1);
+        let address_with_storage = Address::with_last_byte(2);
+        let chain_spec = Arc::new(ChainSpec {
+            chain: Chain::from_id(1),
+            genesis: Genesis {
+                alloc: BTreeMap::from([
+                    (
+                        address_with_code,
+                        GenesisAccount {
+                            code: Some(vec![0x60, 0x00].into()),
+                            ..Default::default()
+                        },
+                    ),
+                    (
+                        address_with_storage,
+                        GenesisAccount {
+                            storage: Some(BTreeMap::from([(
+                                B256::with_last_byte(1),
+                                B256::random(),
+                            )])),
+                            ..Default::default()
+                        },
+                    ),
+                ]),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        let factory = create_test_provider_factory_with_chain_spec(chain_spec.clone());
+        let static_file_provider = factory.static_file_provider();
+        let alloc = &chain_spec.genesis.alloc;
+
+        let mut inserted = Vec::new();
+        for _ in 0..2 {
+            let provider_rw = factory.provider_rw().unwrap();
+            insert_genesis_header(&provider_rw, &static_file_provider, chain_spec.clone()).unwrap();
+            insert_genesis_state(&provider_rw, alloc.len(), alloc.iter()).unwrap();
+            UnifiedStorageWriter::commit(provider_rw, static_file_provider.clone()).unwrap();
+
+            let provider = factory.provider().unwrap();
+            let tx = provider.tx_ref();
+            inserted.push((
+                collect_table_entries::<Arc<DatabaseEnv>, tables::PlainAccountState>(tx).unwrap(),
+                collect_table_entries::<Arc<DatabaseEnv>, tables::PlainStorageState>(tx).unwrap(),
+                collect_table_entries::<Arc<DatabaseEnv>, tables::AccountChangeSets>(tx).unwrap(),
+                collect_table_entries::<Arc<DatabaseEnv>, tables::StorageChangeSets>(tx).unwrap(),
+                collect_table_entries::<Arc<DatabaseEnv>, tables::Bytecodes>(tx).unwrap(),
+                collect_table_entries::<Arc<DatabaseEnv>, tables::HeaderNumbers>(tx).unwrap(),
+            ));
+        }
+
+        assert_eq!(inserted[0].2.len(), 2);
+        assert_eq!(inserted[0].3.len(), 1);
+        assert_eq!(inserted[0], inserted[1]);
+        assert_eq!(
+            static_file_provider.get_highest_static_file_block(StaticFileSegment::Headers),
+            Some(0)
+        );
+    }
 }
@@ -401,6 +401,9 @@ tables! {
     /// Stores the highest pruned block number and prune mode of each prune segment.
     table PruneCheckpoints<Key = PruneSegment, Value = PruneCheckpoint>;
 
+    /// Stores the unix timestamp in seconds, as 8 big-endian bytes, followed by the UTF-8 encoded tag of the subsystem that last saved the prune checkpoint of each prune segment.
+    table PruneCheckpointSources<Key = PruneSegment, Value = Vec<u8>>;
+
     /// Stores the history of client versions that have accessed the database with write privileges by unix timestamp in seconds.
     table VersionHistory<Key = u64, Value = ClientVersion>;
 
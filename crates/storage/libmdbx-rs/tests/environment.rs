@@ -81,6 +81,26 @@ fn test_sync() {
     }
 }
 
+#[test]
+fn test_copy() {
+    let dir = tempdir().unwrap();
+    let env = Environment::builder().open(dir.path()).unwrap();
+
+    let tx = env.begin_rw_txn().unwrap();
+    tx.put(tx.open_db(None).unwrap().dbi(), b"key", b"value", WriteFlags::default()).unwrap();
+    tx.commit().unwrap();
+
+    let copy_dir = tempdir().unwrap();
+    env.copy(&copy_dir.path().join("mdbx.dat"), true).unwrap();
+    // the destination file must not exist yet
+    assert!(env.copy(&copy_dir.path().join("mdbx.dat"), false).is_err());
+
+    let copy = Environment::builder().open(copy_dir.path()).unwrap();
+    let tx = copy.begin_ro_txn().unwrap();
+    let db = tx.open_db(None).unwrap();
+    assert_eq!(tx.get(db.dbi(), b"key").unwrap(), Some(*b"value"));
+}
+
 #[test]
 fn test_stat() {
     let dir = tempdir().unwrap();
@@ -1168,6 +1168,17 @@ where
     fn get_prune_checkpoints(&self) -> ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>> {
         self.database.provider()?.get_prune_checkpoints()
     }
+
+    fn get_prune_checkpoint_source(&self, segment: PruneSegment) -> ProviderResult<Option<String>> {
+        self.database.provider()?.get_prune_checkpoint_source(segment)
+    }
+
+    fn get_prune_checkpoint_last_updated(
+        &self,
+        segment: PruneSegment,
+    ) -> ProviderResult<Option<u64>> {
+        self.database.provider()?.get_prune_checkpoint_last_updated(segment)
+    }
 }
 
 impl<DB> ChainSpecProvider for BlockchainProvider2<DB>
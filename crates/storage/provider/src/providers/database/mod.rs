@@ -589,6 +589,17 @@ impl<DB: Database> PruneCheckpointReader for ProviderFactory<DB> {
     fn get_prune_checkpoints(&self) -> ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>> {
         self.provider()?.get_prune_checkpoints()
     }
+
+    fn get_prune_checkpoint_source(&self, segment: PruneSegment) -> ProviderResult<Option<String>> {
+        self.provider()?.get_prune_checkpoint_source(segment)
+    }
+
+    fn get_prune_checkpoint_last_updated(
+        &self,
+        segment: PruneSegment,
+    ) -> ProviderResult<Option<u64>> {
+        self.provider()?.get_prune_checkpoint_last_updated(segment)
+    }
 }
 
 impl<DB> Clone for ProviderFactory<DB> {
@@ -607,9 +618,11 @@ mod tests {
     use crate::{
         providers::{StaticFileProvider, StaticFileWriter},
         test_utils::{blocks::TEST_BLOCK, create_test_provider_factory},
-        BlockHashReader, BlockNumReader, BlockWriter, HeaderSyncGapProvider, TransactionsProvider,
+        BlockHashReader, BlockNumReader, BlockWriter, HeaderSyncGapProvider, PruneCheckpointWriter,
+        PruneProgressReader, StageCheckpointWriter, TransactionsProvider,
     };
     use assert_matches::assert_matches;
+    use parking_lot::Mutex;
     use rand::Rng;
     use reth_chainspec::ChainSpecBuilder;
     use reth_db::{
@@ -624,8 +637,39 @@ mod tests {
         generators,
         generators::{random_block, random_header},
     };
-    use std::{ops::RangeInclusive, sync::Arc};
+    use std::{collections::HashMap, fmt::Debug, ops::RangeInclusive, sync::Arc};
     use tokio::sync::watch;
+    use tracing::{field::Field, span, Event, Metadata};
+
+    /// Records the fields of every event with the `prune` target.
+    #[derive(Clone, Default)]
+    struct PruneEvents(Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+    impl tracing::Subscriber for PruneEvents {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == "prune"
+        }
+
+        fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = HashMap::new();
+            event.record(&mut |field: &Field, value: &dyn Debug| {
+                fields.insert(field.name().to_string(), format!("{value:?}"));
+            });
+            self.0.lock().push(fields);
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
 
     #[test]
     fn common_history_provider() {
@@ -779,4 +823,72 @@ mod tests {
         assert_eq!(gap.local_head, head);
         assert_eq!(gap.target.tip(), consensus_tip.into());
     }
+
+    #[test]
+    fn save_prune_checkpoint_logs_previous_block() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let events = PruneEvents::default();
+        tracing::subscriber::with_default(events.clone(), || {
+            for block_number in [10, 20] {
+                let checkpoint = PruneCheckpoint {
+                    block_number: Some(block_number),
+                    tx_number: None,
+                    prune_mode: PruneMode::Distance(64),
+                };
+                provider.save_prune_checkpoint(PruneSegment::Receipts, checkpoint).unwrap();
+            }
+        });
+
+        let events = events.0.lock();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["old_block"], "None");
+        assert_eq!(events[1]["segment"], "Receipts");
+        assert_eq!(events[1]["old_block"], "Some(10)");
+        assert_eq!(events[1]["new_block"], "Some(20)");
+        assert_eq!(events[1]["prune_mode"], "Distance(64)");
+    }
+
+    #[test]
+    fn prune_progress_relative_to_best_block() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+        assert_eq!(provider.prune_progress(PruneSegment::Receipts).unwrap(), None);
+
+        provider.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(200)).unwrap();
+        let checkpoint = PruneCheckpoint {
+            block_number: Some(50),
+            tx_number: None,
+            prune_mode: PruneMode::Distance(150),
+        };
+        provider.save_prune_checkpoint(PruneSegment::Receipts, checkpoint).unwrap();
+        assert_eq!(provider.prune_progress(PruneSegment::Receipts).unwrap(), Some(0.25));
+    }
+
+    #[test]
+    fn prune_checkpoint_source_and_last_updated() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+        let segment = PruneSegment::Receipts;
+        assert_eq!(provider.get_prune_checkpoint_last_updated(segment).unwrap(), None);
+
+        let checkpoint = PruneCheckpoint {
+            block_number: Some(10),
+            tx_number: None,
+            prune_mode: PruneMode::Full,
+        };
+        provider.save_prune_checkpoint_tagged(segment, checkpoint, "pruner").unwrap();
+        assert_eq!(
+            provider.get_prune_checkpoint_source(segment).unwrap().as_deref(),
+            Some("pruner")
+        );
+        assert!(provider
+            .get_prune_checkpoint_last_updated(segment)
+            .unwrap()
+            .is_some_and(|last_updated| last_updated > 0));
+
+        provider.delete_prune_checkpoint(segment).unwrap();
+        assert_eq!(provider.get_prune_checkpoint_last_updated(segment).unwrap(), None);
+    }
 }
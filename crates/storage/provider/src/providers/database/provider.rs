@@ -62,7 +62,7 @@ use std::{
     fmt::Debug,
     ops::{Bound, Deref, DerefMut, Range, RangeBounds, RangeInclusive},
     sync::{mpsc, Arc},
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 use tokio::sync::watch;
 use tracing::{debug, error, warn};
@@ -3637,15 +3637,137 @@ impl<TX: DbTx> PruneCheckpointReader for DatabaseProvider<TX> {
             .walk(None)?
             .collect::<Result<_, _>>()?)
     }
+
+    fn get_prune_checkpoint_source(&self, segment: PruneSegment) -> ProviderResult<Option<String>> {
+        Ok(self
+            .tx
+            .get::<tables::PruneCheckpointSources>(segment)?
+            .map(|value| decode_prune_checkpoint_source(&value).0))
+    }
+
+    fn get_prune_checkpoint_last_updated(
+        &self,
+        segment: PruneSegment,
+    ) -> ProviderResult<Option<u64>> {
+        Ok(self
+            .tx
+            .get::<tables::PruneCheckpointSources>(segment)?
+            .and_then(|value| decode_prune_checkpoint_source(&value).1))
+    }
+}
+
+/// Encodes a [`tables::PruneCheckpointSources`] value: the current unix timestamp in seconds as
+/// big-endian bytes, followed by the source tag.
+fn encode_prune_checkpoint_source(source: &str) -> Vec<u8> {
+    let updated_at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let mut value = Vec::with_capacity(8 + source.len());
+    value.extend_from_slice(&updated_at.to_be_bytes());
+    value.extend_from_slice(source.as_bytes());
+    value
+}
+
+/// Decodes a [`tables::PruneCheckpointSources`] value into the source tag and the unix timestamp
+/// of the update.
+///
+/// Values too short to hold a timestamp are read as a bare source tag.
+fn decode_prune_checkpoint_source(value: &[u8]) -> (String, Option<u64>) {
+    match value.split_first_chunk::<8>() {
+        Some((updated_at, source)) => {
+            (String::from_utf8_lossy(source).into_owned(), Some(u64::from_be_bytes(*updated_at)))
+        }
+        None => (String::from_utf8_lossy(value).into_owned(), None),
+    }
 }
 
-impl<TX: DbTxMut> PruneCheckpointWriter for DatabaseProvider<TX> {
-    fn save_prune_checkpoint(
+/// Logs that the checkpoint of the given segment advanced from `previous` to `checkpoint`.
+fn log_prune_checkpoint_update(
+    segment: PruneSegment,
+    previous: Option<PruneCheckpoint>,
+    checkpoint: &PruneCheckpoint,
+    source: &str,
+) {
+    debug!(
+        target: "prune",
+        ?segment,
+        old_block = ?previous.and_then(|previous| previous.block_number),
+        new_block = ?checkpoint.block_number,
+        prune_mode = ?checkpoint.prune_mode,
+        source,
+        "Saved prune checkpoint"
+    );
+}
+
+impl<TX: DbTxMut + DbTx> PruneCheckpointWriter for DatabaseProvider<TX> {
+    fn save_prune_checkpoint_tagged(
         &self,
         segment: PruneSegment,
         checkpoint: PruneCheckpoint,
+        source: &str,
     ) -> ProviderResult<()> {
-        Ok(self.tx.put::<tables::PruneCheckpoints>(segment, checkpoint)?)
+        let previous = self.tx.get::<tables::PruneCheckpoints>(segment)?;
+        self.tx.put::<tables::PruneCheckpoints>(segment, checkpoint)?;
+        self.tx.put::<tables::PruneCheckpointSources>(
+            segment,
+            encode_prune_checkpoint_source(source),
+        )?;
+        log_prune_checkpoint_update(segment, previous, &checkpoint, source);
+        Ok(())
+    }
+
+    fn save_prune_checkpoints(
+        &self,
+        checkpoints: &[(PruneSegment, PruneCheckpoint)],
+        source: &str,
+    ) -> ProviderResult<()> {
+        let mut checkpoints_cursor = self.tx.cursor_write::<tables::PruneCheckpoints>()?;
+        let mut sources_cursor = self.tx.cursor_write::<tables::PruneCheckpointSources>()?;
+        let encoded_source = encode_prune_checkpoint_source(source);
+        for (segment, checkpoint) in checkpoints {
+            let previous = checkpoints_cursor.seek_exact(*segment)?.map(|(_, previous)| previous);
+            checkpoints_cursor.upsert(*segment, *checkpoint)?;
+            sources_cursor.upsert(*segment, encoded_source.clone())?;
+            log_prune_checkpoint_update(*segment, previous, checkpoint, source);
+        }
+        Ok(())
+    }
+
+    fn delete_prune_checkpoint(&self, segment: PruneSegment) -> ProviderResult<()> {
+        self.tx.delete::<tables::PruneCheckpoints>(segment, None)?;
+        self.tx.delete::<tables::PruneCheckpointSources>(segment, None)?;
+        Ok(())
+    }
+
+    fn save_prune_checkpoint_if<F>(
+        &self,
+        segment: PruneSegment,
+        checkpoint: PruneCheckpoint,
+        predicate: F,
+    ) -> ProviderResult<bool>
+    where
+        F: FnOnce(Option<&PruneCheckpoint>) -> bool,
+    {
+        let previous = self.tx.get::<tables::PruneCheckpoints>(segment)?;
+        if !predicate(previous.as_ref()) {
+            return Ok(false)
+        }
+        self.save_prune_checkpoint(segment, checkpoint)?;
+        Ok(true)
+    }
+
+    fn advance_all_prune_checkpoints(
+        &self,
+        delta: u64,
+    ) -> ProviderResult<Vec<(PruneSegment, BlockNumber)>> {
+        let mut advanced = Vec::new();
+        for (segment, mut checkpoint) in self.get_prune_checkpoints()? {
+            let Some(block_number) = checkpoint.block_number else { continue };
+
+            let block_number = block_number.saturating_add(delta);
+            checkpoint.block_number = Some(block_number);
+            self.save_prune_checkpoint(segment, checkpoint)?;
+            advanced.push((segment, block_number));
+        }
+        Ok(advanced)
     }
 }
 
@@ -542,6 +542,20 @@ impl PruneCheckpointReader for NoopProvider {
     fn get_prune_checkpoints(&self) -> ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>> {
         Ok(Vec::new())
     }
+
+    fn get_prune_checkpoint_source(
+        &self,
+        _segment: PruneSegment,
+    ) -> ProviderResult<Option<String>> {
+        Ok(None)
+    }
+
+    fn get_prune_checkpoint_last_updated(
+        &self,
+        _segment: PruneSegment,
+    ) -> ProviderResult<Option<u64>> {
+        Ok(None)
+    }
 }
 
 impl StaticFileProviderFactory for NoopProvider {
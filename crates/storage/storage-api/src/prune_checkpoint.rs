@@ -1,5 +1,15 @@
-use reth_prune_types::{PruneCheckpoint, PruneSegment};
+use crate::BlockNumReader;
+use parking_lot::{Mutex, MutexGuard, RwLock};
+use reth_codecs::Compact;
+use reth_primitives::BlockNumber;
+use reth_prune_types::{PruneCheckpoint, PruneModeKind, PruneSegment, PruneSegmentState};
 use reth_storage_errors::provider::ProviderResult;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    future::Future,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 /// The trait for fetching prune checkpoint related data.
 #[auto_impl::auto_impl(&, Arc)]
@@ -10,17 +20,976 @@ pub trait PruneCheckpointReader: Send + Sync {
         segment: PruneSegment,
     ) -> ProviderResult<Option<PruneCheckpoint>>;
 
-    /// Fetch all the prune checkpoints.
-    fn get_prune_checkpoints(&self) -> ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>>;
+    /// Fetch all the prune checkpoints, sorted by segment.
+    ///
+    /// The default implementation looks up every segment in [`PruneSegment::ALL`] separately, so
+    /// implementations backed by a table should override it with a single table walk.
+    fn get_prune_checkpoints(&self) -> ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>> {
+        let mut checkpoints = Vec::new();
+        for segment in PruneSegment::ALL {
+            if let Some(checkpoint) = self.get_prune_checkpoint(segment)? {
+                checkpoints.push((segment, checkpoint));
+            }
+        }
+        checkpoints.sort_unstable_by_key(|(segment, _)| *segment);
+        Ok(checkpoints)
+    }
+
+    /// Fetch the tag of the source that last saved the prune checkpoint for the given segment.
+    fn get_prune_checkpoint_source(&self, segment: PruneSegment) -> ProviderResult<Option<String>>;
+
+    /// Fetch the unix timestamp in seconds of when the prune checkpoint for the given segment was
+    /// last saved.
+    fn get_prune_checkpoint_last_updated(
+        &self,
+        segment: PruneSegment,
+    ) -> ProviderResult<Option<u64>>;
+
+    /// Estimates how long it takes the given segment to catch up to `tip`, if the pruner keeps
+    /// advancing at `blocks_per_sec`.
+    ///
+    /// Returns `None` if the segment has no checkpoint with a block number, is already caught up,
+    /// or if `blocks_per_sec` is not a positive rate.
+    fn prune_eta(
+        &self,
+        segment: PruneSegment,
+        tip: BlockNumber,
+        blocks_per_sec: f64,
+    ) -> ProviderResult<Option<Duration>> {
+        if blocks_per_sec.is_nan() || blocks_per_sec <= 0.0 {
+            return Ok(None)
+        }
+
+        let Some(checkpoint_block) =
+            self.get_prune_checkpoint(segment)?.and_then(|checkpoint| checkpoint.block_number)
+        else {
+            return Ok(None)
+        };
+
+        let remaining = tip.saturating_sub(checkpoint_block);
+        if remaining == 0 {
+            return Ok(None)
+        }
+
+        Ok(Duration::try_from_secs_f64(remaining as f64 / blocks_per_sec).ok())
+    }
+
+    /// Groups all prune segments by the variant of the prune mode stored in their checkpoint.
+    ///
+    /// Segments without a checkpoint are grouped under [`PruneModeKind::Unset`]. Segments within
+    /// a group are sorted.
+    fn prune_segments_by_mode(&self) -> ProviderResult<BTreeMap<PruneModeKind, Vec<PruneSegment>>> {
+        let checkpoints = self.get_prune_checkpoints()?.into_iter().collect::<HashMap<_, _>>();
+
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for segment in PruneSegment::ALL {
+            let kind = checkpoints
+                .get(&segment)
+                .map_or(PruneModeKind::Unset, |checkpoint| checkpoint.prune_mode.kind());
+            groups.entry(kind).or_default().push(segment);
+        }
+        Ok(groups)
+    }
+
+    /// Returns the pruning state of every segment in [`PruneSegment::ALL`] relative to `tip`,
+    /// sorted by segment, from a single lookup of all checkpoints.
+    ///
+    /// The last update time is looked up separately for each segment that has a checkpoint.
+    /// Segments without a checkpoint are included with all fields unset.
+    fn prune_state_overview(&self, tip: BlockNumber) -> ProviderResult<Vec<PruneSegmentState>> {
+        let checkpoints = self.get_prune_checkpoints()?.into_iter().collect::<HashMap<_, _>>();
+
+        let mut overview = Vec::with_capacity(PruneSegment::ALL.len());
+        for segment in PruneSegment::ALL {
+            let checkpoint = checkpoints.get(&segment);
+            let block_number = checkpoint.and_then(|checkpoint| checkpoint.block_number);
+            let last_updated = if checkpoint.is_some() {
+                self.get_prune_checkpoint_last_updated(segment)?
+            } else {
+                None
+            };
+            overview.push(PruneSegmentState {
+                segment,
+                block_number,
+                lag: block_number.map(|block_number| tip.saturating_sub(block_number)),
+                prune_mode: checkpoint.map(|checkpoint| checkpoint.prune_mode),
+                last_updated,
+            });
+        }
+        overview.sort_unstable_by_key(|state| state.segment);
+        Ok(overview)
+    }
+}
+
+/// Relates the prune checkpoints to the best block of the chain.
+///
+/// Implemented for every provider that can read both.
+pub trait PruneProgressReader: PruneCheckpointReader + BlockNumReader {
+    /// Returns how far the given segment is pruned relative to the best block, computed as
+    /// `pruned_block / best_block` and capped at `1.0`.
+    ///
+    /// Returns `None` if the segment has no checkpoint with a block number.
+    fn prune_progress(&self, segment: PruneSegment) -> ProviderResult<Option<f64>> {
+        let Some(pruned_block) =
+            self.get_prune_checkpoint(segment)?.and_then(|checkpoint| checkpoint.block_number)
+        else {
+            return Ok(None)
+        };
+
+        let best_block = self.best_block_number()?;
+        if best_block == 0 {
+            return Ok(Some(1.0))
+        }
+        Ok(Some((pruned_block as f64 / best_block as f64).min(1.0)))
+    }
+}
+
+impl<T> PruneProgressReader for T where T: PruneCheckpointReader + BlockNumReader {}
+
+/// The async counterpart of [`PruneCheckpointReader`], for callers that must not block the async
+/// runtime.
+///
+/// Any [`PruneCheckpointReader`] can be used through [`BlockingPruneCheckpointReader`].
+pub trait AsyncPruneCheckpointReader: Send + Sync {
+    /// Fetch the prune checkpoint for the given segment.
+    fn get_prune_checkpoint(
+        &self,
+        segment: PruneSegment,
+    ) -> impl Future<Output = ProviderResult<Option<PruneCheckpoint>>> + Send;
+
+    /// Fetch all the prune checkpoints, sorted by segment.
+    fn get_prune_checkpoints(
+        &self,
+    ) -> impl Future<Output = ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>>> + Send;
 }
 
+/// Implements [`AsyncPruneCheckpointReader`] for a [`PruneCheckpointReader`] by running each
+/// lookup on the blocking thread pool of the tokio runtime.
+///
+/// Must be used from within a tokio runtime.
+#[derive(Debug)]
+pub struct BlockingPruneCheckpointReader<R> {
+    inner: Arc<R>,
+}
+
+impl<R> BlockingPruneCheckpointReader<R> {
+    /// Creates a new adapter for the given reader.
+    pub fn new(reader: R) -> Self {
+        Self { inner: Arc::new(reader) }
+    }
+
+    /// Runs `f` with the inner reader on the blocking thread pool.
+    ///
+    /// Panics of `f` are propagated to the caller.
+    async fn spawn_blocking<F, T>(&self, f: F) -> T
+    where
+        R: Send + Sync + 'static,
+        F: FnOnce(&R) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let inner = Arc::clone(&self.inner);
+        match tokio::task::spawn_blocking(move || f(&inner)).await {
+            Ok(result) => result,
+            Err(err) => std::panic::resume_unwind(err.into_panic()),
+        }
+    }
+}
+
+impl<R> Clone for BlockingPruneCheckpointReader<R> {
+    fn clone(&self) -> Self {
+        Self { inner: Arc::clone(&self.inner) }
+    }
+}
+
+impl<R: PruneCheckpointReader + 'static> AsyncPruneCheckpointReader
+    for BlockingPruneCheckpointReader<R>
+{
+    fn get_prune_checkpoint(
+        &self,
+        segment: PruneSegment,
+    ) -> impl Future<Output = ProviderResult<Option<PruneCheckpoint>>> + Send {
+        self.spawn_blocking(move |reader| reader.get_prune_checkpoint(segment))
+    }
+
+    fn get_prune_checkpoints(
+        &self,
+    ) -> impl Future<Output = ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>>> + Send {
+        self.spawn_blocking(|reader| reader.get_prune_checkpoints())
+    }
+}
+
+/// The source tag of prune checkpoints saved without an explicit source.
+pub const DEFAULT_PRUNE_CHECKPOINT_SOURCE: &str = "untagged";
+
 /// The trait for updating prune checkpoint related data.
 #[auto_impl::auto_impl(&, Arc)]
 pub trait PruneCheckpointWriter: Send + Sync {
-    /// Save prune checkpoint.
+    /// Save prune checkpoint, tagged with [`DEFAULT_PRUNE_CHECKPOINT_SOURCE`].
     fn save_prune_checkpoint(
         &self,
         segment: PruneSegment,
         checkpoint: PruneCheckpoint,
+    ) -> ProviderResult<()> {
+        self.save_prune_checkpoint_tagged(segment, checkpoint, DEFAULT_PRUNE_CHECKPOINT_SOURCE)
+    }
+
+    /// Save prune checkpoint together with a tag of the subsystem that saved it.
+    ///
+    /// The tag replaces the one stored for the previous checkpoint of the segment and can be read
+    /// back with [`PruneCheckpointReader::get_prune_checkpoint_source`].
+    fn save_prune_checkpoint_tagged(
+        &self,
+        segment: PruneSegment,
+        checkpoint: PruneCheckpoint,
+        source: &str,
     ) -> ProviderResult<()>;
+
+    /// Save the prune checkpoints of several segments, all tagged with the given source.
+    ///
+    /// The default implementation saves them one by one, implementations backed by a table should
+    /// override it to write them with a single cursor.
+    fn save_prune_checkpoints(
+        &self,
+        checkpoints: &[(PruneSegment, PruneCheckpoint)],
+        source: &str,
+    ) -> ProviderResult<()> {
+        for (segment, checkpoint) in checkpoints {
+            self.save_prune_checkpoint_tagged(*segment, *checkpoint, source)?;
+        }
+        Ok(())
+    }
+
+    /// Delete the prune checkpoint of the given segment together with its source tag.
+    ///
+    /// Does nothing if the segment has no checkpoint.
+    fn delete_prune_checkpoint(&self, segment: PruneSegment) -> ProviderResult<()>;
+
+    /// Save prune checkpoint only if `predicate` holds for the currently stored checkpoint of the
+    /// segment.
+    ///
+    /// The previous checkpoint is read and the new one written within the same transaction, so no
+    /// other write can happen in between. Returns whether the checkpoint was saved.
+    fn save_prune_checkpoint_if<F>(
+        &self,
+        segment: PruneSegment,
+        checkpoint: PruneCheckpoint,
+        predicate: F,
+    ) -> ProviderResult<bool>
+    where
+        F: FnOnce(Option<&PruneCheckpoint>) -> bool;
+
+    /// Advance the block number of every stored prune checkpoint by `delta` within one
+    /// transaction.
+    ///
+    /// Checkpoints without a block number are skipped. Returns the segments that were advanced
+    /// together with their new block numbers.
+    fn advance_all_prune_checkpoints(
+        &self,
+        delta: u64,
+    ) -> ProviderResult<Vec<(PruneSegment, BlockNumber)>>;
+}
+
+/// Compares two captured sets of prune checkpoints and returns the segments whose block number
+/// went backwards from `before` to `after`.
+///
+/// Only segments present in both sets are compared. A checkpoint that lost its block number counts
+/// as a regression. The returned segments are sorted and deduplicated.
+pub fn assert_no_prune_regression(
+    before: &[(PruneSegment, PruneCheckpoint)],
+    after: &[(PruneSegment, PruneCheckpoint)],
+) -> Result<(), Vec<PruneSegment>> {
+    let before = before
+        .iter()
+        .map(|(segment, checkpoint)| (*segment, checkpoint.block_number))
+        .collect::<HashMap<_, _>>();
+
+    let mut regressed = after
+        .iter()
+        .filter(|(segment, checkpoint)| {
+            before.get(segment).is_some_and(|block_number| checkpoint.block_number < *block_number)
+        })
+        .map(|(segment, _)| *segment)
+        .collect::<Vec<_>>();
+    if regressed.is_empty() {
+        return Ok(())
+    }
+
+    regressed.sort_unstable();
+    regressed.dedup();
+    Err(regressed)
+}
+
+/// An in-memory store of prune checkpoints, implementing both [`PruneCheckpointReader`] and
+/// [`PruneCheckpointWriter`].
+///
+/// Checkpoints are kept in their [`Compact`] encoding, the same one used by the database table, so
+/// that save-then-get round-trips exercise checkpoint serialization without a real database.
+#[derive(Debug, Default)]
+pub struct MemoryPruneCheckpointStore {
+    checkpoints: RwLock<HashMap<PruneSegment, Vec<u8>>>,
+    /// The source tag and unix timestamp in seconds of the last save of each segment.
+    sources: RwLock<HashMap<PruneSegment, (String, u64)>>,
+}
+
+impl MemoryPruneCheckpointStore {
+    /// Creates a new empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl PruneCheckpointReader for MemoryPruneCheckpointStore {
+    fn get_prune_checkpoint(
+        &self,
+        segment: PruneSegment,
+    ) -> ProviderResult<Option<PruneCheckpoint>> {
+        Ok(self
+            .checkpoints
+            .read()
+            .get(&segment)
+            .map(|encoded| PruneCheckpoint::from_compact(encoded, encoded.len()).0))
+    }
+
+    fn get_prune_checkpoints(&self) -> ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>> {
+        let mut checkpoints = self
+            .checkpoints
+            .read()
+            .iter()
+            .map(|(segment, encoded)| {
+                (*segment, PruneCheckpoint::from_compact(encoded, encoded.len()).0)
+            })
+            .collect::<Vec<_>>();
+        // Match the ordering of a database table walk, which is sorted by key.
+        checkpoints.sort_unstable_by_key(|(segment, _)| *segment);
+        Ok(checkpoints)
+    }
+
+    fn get_prune_checkpoint_source(&self, segment: PruneSegment) -> ProviderResult<Option<String>> {
+        Ok(self.sources.read().get(&segment).map(|(source, _)| source.clone()))
+    }
+
+    fn get_prune_checkpoint_last_updated(
+        &self,
+        segment: PruneSegment,
+    ) -> ProviderResult<Option<u64>> {
+        Ok(self.sources.read().get(&segment).map(|(_, updated_at)| *updated_at))
+    }
+}
+
+impl PruneCheckpointWriter for MemoryPruneCheckpointStore {
+    fn save_prune_checkpoint_tagged(
+        &self,
+        segment: PruneSegment,
+        checkpoint: PruneCheckpoint,
+        source: &str,
+    ) -> ProviderResult<()> {
+        let mut encoded = Vec::new();
+        checkpoint.to_compact(&mut encoded);
+        self.checkpoints.write().insert(segment, encoded);
+        self.sources.write().insert(segment, (source.to_string(), unix_timestamp()));
+        Ok(())
+    }
+
+    fn delete_prune_checkpoint(&self, segment: PruneSegment) -> ProviderResult<()> {
+        self.checkpoints.write().remove(&segment);
+        self.sources.write().remove(&segment);
+        Ok(())
+    }
+
+    fn save_prune_checkpoint_if<F>(
+        &self,
+        segment: PruneSegment,
+        checkpoint: PruneCheckpoint,
+        predicate: F,
+    ) -> ProviderResult<bool>
+    where
+        F: FnOnce(Option<&PruneCheckpoint>) -> bool,
+    {
+        // Hold the write lock for the whole read-then-write to match the database transaction
+        let mut checkpoints = self.checkpoints.write();
+        let previous = checkpoints
+            .get(&segment)
+            .map(|encoded| PruneCheckpoint::from_compact(encoded, encoded.len()).0);
+        if !predicate(previous.as_ref()) {
+            return Ok(false)
+        }
+
+        let mut encoded = Vec::new();
+        checkpoint.to_compact(&mut encoded);
+        checkpoints.insert(segment, encoded);
+        self.sources
+            .write()
+            .insert(segment, (DEFAULT_PRUNE_CHECKPOINT_SOURCE.to_string(), unix_timestamp()));
+        Ok(true)
+    }
+
+    fn advance_all_prune_checkpoints(
+        &self,
+        delta: u64,
+    ) -> ProviderResult<Vec<(PruneSegment, BlockNumber)>> {
+        let mut checkpoints = self.checkpoints.write();
+        let mut sources = self.sources.write();
+        let updated_at = unix_timestamp();
+        let mut advanced = Vec::new();
+        for (segment, encoded) in checkpoints.iter_mut() {
+            let mut checkpoint = PruneCheckpoint::from_compact(encoded, encoded.len()).0;
+            let Some(block_number) = checkpoint.block_number else { continue };
+
+            let block_number = block_number.saturating_add(delta);
+            checkpoint.block_number = Some(block_number);
+            encoded.clear();
+            checkpoint.to_compact(encoded);
+            sources.insert(*segment, (DEFAULT_PRUNE_CHECKPOINT_SOURCE.to_string(), updated_at));
+            advanced.push((*segment, block_number));
+        }
+        advanced.sort_unstable_by_key(|(segment, _)| *segment);
+        Ok(advanced)
+    }
+}
+
+/// Returns the current unix timestamp in seconds.
+fn unix_timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// Wraps a prune checkpoint store and serializes updates per [`PruneSegment`].
+///
+/// Updates to the same segment are applied one after another, while updates to different
+/// segments don't wait for each other. Updates spanning several segments lock them in
+/// [`PruneSegment`] order, so they can't deadlock with each other. Reads are passed through to the
+/// inner store without locking.
+#[derive(Debug)]
+pub struct SegmentLockedPruneCheckpointStore<S> {
+    inner: S,
+    locks: BTreeMap<PruneSegment, Mutex<()>>,
+}
+
+impl<S> SegmentLockedPruneCheckpointStore<S> {
+    /// Creates a new wrapper around the given store.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            locks: PruneSegment::ALL.into_iter().map(|segment| (segment, Mutex::new(()))).collect(),
+        }
+    }
+
+    /// Returns the inner store.
+    pub const fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Locks the given segment until the returned guard is dropped.
+    fn lock(&self, segment: PruneSegment) -> MutexGuard<'_, ()> {
+        self.locks[&segment].lock()
+    }
+
+    /// Locks all the given segments in [`PruneSegment`] order until the returned guards are
+    /// dropped.
+    fn lock_all(
+        &self,
+        segments: impl IntoIterator<Item = PruneSegment>,
+    ) -> Vec<MutexGuard<'_, ()>> {
+        let segments = segments.into_iter().collect::<BTreeSet<_>>();
+        segments.into_iter().map(|segment| self.lock(segment)).collect()
+    }
+}
+
+impl<S: PruneCheckpointReader + PruneCheckpointWriter> SegmentLockedPruneCheckpointStore<S> {
+    /// Reads the checkpoint of the given segment, passes it to `f` and saves the returned
+    /// checkpoint, without any other update to the segment in between.
+    pub fn update_prune_checkpoint<F>(&self, segment: PruneSegment, f: F) -> ProviderResult<()>
+    where
+        F: FnOnce(Option<PruneCheckpoint>) -> PruneCheckpoint,
+    {
+        let _guard = self.lock(segment);
+        let checkpoint = f(self.inner.get_prune_checkpoint(segment)?);
+        self.inner.save_prune_checkpoint(segment, checkpoint)
+    }
+}
+
+impl<S: PruneCheckpointReader> PruneCheckpointReader for SegmentLockedPruneCheckpointStore<S> {
+    fn get_prune_checkpoint(
+        &self,
+        segment: PruneSegment,
+    ) -> ProviderResult<Option<PruneCheckpoint>> {
+        self.inner.get_prune_checkpoint(segment)
+    }
+
+    fn get_prune_checkpoints(&self) -> ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>> {
+        self.inner.get_prune_checkpoints()
+    }
+
+    fn get_prune_checkpoint_source(&self, segment: PruneSegment) -> ProviderResult<Option<String>> {
+        self.inner.get_prune_checkpoint_source(segment)
+    }
+
+    fn get_prune_checkpoint_last_updated(
+        &self,
+        segment: PruneSegment,
+    ) -> ProviderResult<Option<u64>> {
+        self.inner.get_prune_checkpoint_last_updated(segment)
+    }
+}
+
+impl<S: PruneCheckpointWriter> PruneCheckpointWriter for SegmentLockedPruneCheckpointStore<S> {
+    fn save_prune_checkpoint_tagged(
+        &self,
+        segment: PruneSegment,
+        checkpoint: PruneCheckpoint,
+        source: &str,
+    ) -> ProviderResult<()> {
+        let _guard = self.lock(segment);
+        self.inner.save_prune_checkpoint_tagged(segment, checkpoint, source)
+    }
+
+    fn save_prune_checkpoints(
+        &self,
+        checkpoints: &[(PruneSegment, PruneCheckpoint)],
+        source: &str,
+    ) -> ProviderResult<()> {
+        let _guards = self.lock_all(checkpoints.iter().map(|(segment, _)| *segment));
+        self.inner.save_prune_checkpoints(checkpoints, source)
+    }
+
+    fn delete_prune_checkpoint(&self, segment: PruneSegment) -> ProviderResult<()> {
+        let _guard = self.lock(segment);
+        self.inner.delete_prune_checkpoint(segment)
+    }
+
+    fn save_prune_checkpoint_if<F>(
+        &self,
+        segment: PruneSegment,
+        checkpoint: PruneCheckpoint,
+        predicate: F,
+    ) -> ProviderResult<bool>
+    where
+        F: FnOnce(Option<&PruneCheckpoint>) -> bool,
+    {
+        let _guard = self.lock(segment);
+        self.inner.save_prune_checkpoint_if(segment, checkpoint, predicate)
+    }
+
+    fn advance_all_prune_checkpoints(
+        &self,
+        delta: u64,
+    ) -> ProviderResult<Vec<(PruneSegment, BlockNumber)>> {
+        let _guards = self.lock_all(PruneSegment::ALL);
+        self.inner.advance_all_prune_checkpoints(delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{prelude::*, proptest};
+    use reth_prune_types::PruneMode;
+
+    fn arb_segment() -> impl Strategy<Value = PruneSegment> {
+        prop_oneof![
+            Just(PruneSegment::SenderRecovery),
+            Just(PruneSegment::TransactionLookup),
+            Just(PruneSegment::Receipts),
+            Just(PruneSegment::ContractLogs),
+            Just(PruneSegment::AccountHistory),
+            Just(PruneSegment::StorageHistory),
+            Just(PruneSegment::Headers),
+            Just(PruneSegment::Transactions),
+        ]
+    }
+
+    fn arb_checkpoint() -> impl Strategy<Value = PruneCheckpoint> {
+        let prune_mode = prop_oneof![
+            Just(PruneMode::Full),
+            any::<u64>().prop_map(PruneMode::Distance),
+            any::<u64>().prop_map(PruneMode::Before),
+        ];
+        (any::<Option<u64>>(), any::<Option<u64>>(), prune_mode).prop_map(
+            |(block_number, tx_number, prune_mode)| PruneCheckpoint {
+                block_number,
+                tx_number,
+                prune_mode,
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn save_then_get_roundtrip(segment in arb_segment(), checkpoint in arb_checkpoint()) {
+            let store = MemoryPruneCheckpointStore::new();
+            store.save_prune_checkpoint(segment, checkpoint).unwrap();
+
+            prop_assert_eq!(store.get_prune_checkpoint(segment).unwrap(), Some(checkpoint));
+            prop_assert_eq!(store.get_prune_checkpoints().unwrap(), vec![(segment, checkpoint)]);
+        }
+
+        #[test]
+        fn save_overwrites_previous(
+            segment in arb_segment(),
+            first in arb_checkpoint(),
+            second in arb_checkpoint(),
+        ) {
+            let store = MemoryPruneCheckpointStore::new();
+            store.save_prune_checkpoint(segment, first).unwrap();
+            store.save_prune_checkpoint(segment, second).unwrap();
+
+            prop_assert_eq!(store.get_prune_checkpoint(segment).unwrap(), Some(second));
+        }
+    }
+
+    #[test]
+    fn prune_state_overview() {
+        let store = MemoryPruneCheckpointStore::new();
+        let checkpoint = PruneCheckpoint {
+            block_number: Some(90),
+            tx_number: None,
+            prune_mode: PruneMode::Distance(10),
+        };
+        store.save_prune_checkpoint(PruneSegment::Receipts, checkpoint).unwrap();
+
+        let overview = store.prune_state_overview(100).unwrap();
+        assert_eq!(overview.len(), PruneSegment::ALL.len());
+        for state in overview {
+            if state.segment == PruneSegment::Receipts {
+                assert_eq!(state.block_number, Some(90));
+                assert_eq!(state.lag, Some(10));
+                assert_eq!(state.prune_mode, Some(PruneMode::Distance(10)));
+                assert!(state.last_updated.is_some_and(|last_updated| last_updated > 0));
+            } else {
+                assert_eq!(state.block_number, None);
+                assert_eq!(state.lag, None);
+                assert_eq!(state.prune_mode, None);
+                assert_eq!(state.last_updated, None);
+            }
+        }
+    }
+
+    #[test]
+    fn save_prune_checkpoint_tagged() {
+        let store = MemoryPruneCheckpointStore::new();
+        let segment = PruneSegment::Receipts;
+        let checkpoint = PruneCheckpoint {
+            block_number: Some(10),
+            tx_number: None,
+            prune_mode: PruneMode::Full,
+        };
+        assert_eq!(store.get_prune_checkpoint_source(segment).unwrap(), None);
+
+        store.save_prune_checkpoint_tagged(segment, checkpoint, "pruner").unwrap();
+        assert_eq!(store.get_prune_checkpoint(segment).unwrap(), Some(checkpoint));
+        assert_eq!(store.get_prune_checkpoint_source(segment).unwrap().as_deref(), Some("pruner"));
+
+        store.save_prune_checkpoint(segment, checkpoint).unwrap();
+        assert_eq!(
+            store.get_prune_checkpoint_source(segment).unwrap().as_deref(),
+            Some(DEFAULT_PRUNE_CHECKPOINT_SOURCE)
+        );
+    }
+
+    #[test]
+    fn default_get_prune_checkpoints() {
+        /// Only implements the per-segment lookup, to exercise the default batch lookup.
+        struct PerSegmentReader(MemoryPruneCheckpointStore);
+
+        impl PruneCheckpointReader for PerSegmentReader {
+            fn get_prune_checkpoint(
+                &self,
+                segment: PruneSegment,
+            ) -> ProviderResult<Option<PruneCheckpoint>> {
+                self.0.get_prune_checkpoint(segment)
+            }
+
+            fn get_prune_checkpoint_source(
+                &self,
+                segment: PruneSegment,
+            ) -> ProviderResult<Option<String>> {
+                self.0.get_prune_checkpoint_source(segment)
+            }
+
+            fn get_prune_checkpoint_last_updated(
+                &self,
+                segment: PruneSegment,
+            ) -> ProviderResult<Option<u64>> {
+                self.0.get_prune_checkpoint_last_updated(segment)
+            }
+        }
+
+        let store = MemoryPruneCheckpointStore::new();
+        let checkpoint = |block_number| PruneCheckpoint {
+            block_number: Some(block_number),
+            tx_number: None,
+            prune_mode: PruneMode::Full,
+        };
+        store.save_prune_checkpoint(PruneSegment::Transactions, checkpoint(30)).unwrap();
+        store.save_prune_checkpoint(PruneSegment::Receipts, checkpoint(10)).unwrap();
+        store.save_prune_checkpoint(PruneSegment::SenderRecovery, checkpoint(20)).unwrap();
+
+        let expected = store.get_prune_checkpoints().unwrap();
+        assert_eq!(expected.len(), 3);
+        assert_eq!(PerSegmentReader(store).get_prune_checkpoints().unwrap(), expected);
+    }
+
+    #[test]
+    fn save_prune_checkpoints() {
+        let store = MemoryPruneCheckpointStore::new();
+        let checkpoint = |block_number| PruneCheckpoint {
+            block_number: Some(block_number),
+            tx_number: None,
+            prune_mode: PruneMode::Full,
+        };
+        store
+            .save_prune_checkpoint_tagged(PruneSegment::Receipts, checkpoint(5), "pruner")
+            .unwrap();
+
+        store
+            .save_prune_checkpoints(
+                &[
+                    (PruneSegment::Receipts, checkpoint(10)),
+                    (PruneSegment::ContractLogs, checkpoint(10)),
+                ],
+                "prune-stage-unwind",
+            )
+            .unwrap();
+        assert_eq!(
+            store.get_prune_checkpoints().unwrap(),
+            vec![
+                (PruneSegment::Receipts, checkpoint(10)),
+                (PruneSegment::ContractLogs, checkpoint(10)),
+            ]
+        );
+        assert_eq!(
+            store.get_prune_checkpoint_source(PruneSegment::Receipts).unwrap().as_deref(),
+            Some("prune-stage-unwind")
+        );
+    }
+
+    #[test]
+    fn delete_prune_checkpoint() {
+        let store = MemoryPruneCheckpointStore::new();
+        let segment = PruneSegment::Receipts;
+        let checkpoint = PruneCheckpoint {
+            block_number: Some(10),
+            tx_number: None,
+            prune_mode: PruneMode::Full,
+        };
+        store.save_prune_checkpoint_tagged(segment, checkpoint, "pruner").unwrap();
+        store.save_prune_checkpoint(PruneSegment::Headers, checkpoint).unwrap();
+
+        store.delete_prune_checkpoint(segment).unwrap();
+        assert_eq!(store.get_prune_checkpoint(segment).unwrap(), None);
+        assert_eq!(store.get_prune_checkpoint_source(segment).unwrap(), None);
+        assert_eq!(
+            store.get_prune_checkpoints().unwrap(),
+            vec![(PruneSegment::Headers, checkpoint)]
+        );
+
+        // deleting a missing checkpoint is a no-op
+        store.delete_prune_checkpoint(segment).unwrap();
+    }
+
+    #[tokio::test]
+    async fn blocking_prune_checkpoint_reader() {
+        let store = MemoryPruneCheckpointStore::new();
+        let checkpoint = PruneCheckpoint {
+            block_number: Some(10),
+            tx_number: None,
+            prune_mode: PruneMode::Full,
+        };
+        store.save_prune_checkpoint(PruneSegment::Receipts, checkpoint).unwrap();
+
+        let reader = BlockingPruneCheckpointReader::new(store);
+        assert_eq!(
+            reader.get_prune_checkpoint(PruneSegment::Receipts).await.unwrap(),
+            Some(checkpoint)
+        );
+        assert_eq!(reader.get_prune_checkpoint(PruneSegment::Headers).await.unwrap(), None);
+        assert_eq!(
+            reader.get_prune_checkpoints().await.unwrap(),
+            vec![(PruneSegment::Receipts, checkpoint)]
+        );
+    }
+
+    #[test]
+    fn segment_locked_concurrent_updates() {
+        let store = SegmentLockedPruneCheckpointStore::new(MemoryPruneCheckpointStore::new());
+        let increment = |checkpoint: Option<PruneCheckpoint>| PruneCheckpoint {
+            block_number: Some(
+                checkpoint.and_then(|checkpoint| checkpoint.block_number).unwrap_or_default() + 1,
+            ),
+            tx_number: None,
+            prune_mode: PruneMode::Full,
+        };
+
+        // Two threads per segment, so updates to the same segment race with each other
+        std::thread::scope(|scope| {
+            for segment in [PruneSegment::Receipts, PruneSegment::Headers] {
+                for _ in 0..2 {
+                    let store = &store;
+                    scope.spawn(move || {
+                        for _ in 0..100 {
+                            store.update_prune_checkpoint(segment, increment).unwrap();
+                        }
+                    });
+                }
+            }
+            // Updates spanning all segments take the locks in order and don't deadlock
+            scope.spawn(|| store.advance_all_prune_checkpoints(0).unwrap());
+        });
+
+        for segment in [PruneSegment::Receipts, PruneSegment::Headers] {
+            assert_eq!(
+                store
+                    .get_prune_checkpoint(segment)
+                    .unwrap()
+                    .and_then(|checkpoint| checkpoint.block_number),
+                Some(200)
+            );
+        }
+    }
+
+    #[test]
+    fn prune_eta() {
+        let store = MemoryPruneCheckpointStore::new();
+        let segment = PruneSegment::Receipts;
+        assert_eq!(store.prune_eta(segment, 100, 10.0).unwrap(), None);
+
+        let checkpoint = PruneCheckpoint {
+            block_number: Some(50),
+            tx_number: None,
+            prune_mode: PruneMode::Full,
+        };
+        store.save_prune_checkpoint(segment, checkpoint).unwrap();
+
+        assert_eq!(store.prune_eta(segment, 100, 10.0).unwrap(), Some(Duration::from_secs(5)));
+        assert_eq!(store.prune_eta(segment, 50, 10.0).unwrap(), None);
+        assert_eq!(store.prune_eta(segment, 40, 10.0).unwrap(), None);
+        assert_eq!(store.prune_eta(segment, 100, 0.0).unwrap(), None);
+        assert_eq!(store.prune_eta(segment, 100, -1.0).unwrap(), None);
+        assert_eq!(store.prune_eta(segment, 100, f64::NAN).unwrap(), None);
+    }
+
+    #[test]
+    fn save_prune_checkpoint_if() {
+        let store = MemoryPruneCheckpointStore::new();
+        let segment = PruneSegment::Receipts;
+        let checkpoint = |block_number| PruneCheckpoint {
+            block_number: Some(block_number),
+            tx_number: None,
+            prune_mode: PruneMode::Full,
+        };
+        let below = |block_number| {
+            move |previous: Option<&PruneCheckpoint>| match previous {
+                Some(previous) => previous.block_number < Some(block_number),
+                None => true,
+            }
+        };
+
+        // no previous checkpoint
+        assert!(store.save_prune_checkpoint_if(segment, checkpoint(10), below(10)).unwrap());
+        assert_eq!(store.get_prune_checkpoint(segment).unwrap(), Some(checkpoint(10)));
+
+        // predicate does not hold, checkpoint is left untouched
+        assert!(!store.save_prune_checkpoint_if(segment, checkpoint(5), below(5)).unwrap());
+        assert_eq!(store.get_prune_checkpoint(segment).unwrap(), Some(checkpoint(10)));
+
+        // predicate holds
+        assert!(store.save_prune_checkpoint_if(segment, checkpoint(20), below(20)).unwrap());
+        assert_eq!(store.get_prune_checkpoint(segment).unwrap(), Some(checkpoint(20)));
+    }
+
+    #[test]
+    fn advance_all_prune_checkpoints() {
+        let store = MemoryPruneCheckpointStore::new();
+        let checkpoint = |block_number| PruneCheckpoint {
+            block_number,
+            tx_number: None,
+            prune_mode: PruneMode::Full,
+        };
+        store.save_prune_checkpoint(PruneSegment::Receipts, checkpoint(Some(10))).unwrap();
+        store.save_prune_checkpoint(PruneSegment::Headers, checkpoint(Some(20))).unwrap();
+        store.save_prune_checkpoint(PruneSegment::ContractLogs, checkpoint(None)).unwrap();
+
+        assert_eq!(
+            store.advance_all_prune_checkpoints(5).unwrap(),
+            vec![(PruneSegment::Receipts, 15), (PruneSegment::Headers, 25)]
+        );
+        assert_eq!(
+            store.get_prune_checkpoint(PruneSegment::Receipts).unwrap(),
+            Some(checkpoint(Some(15)))
+        );
+        assert_eq!(
+            store.get_prune_checkpoint(PruneSegment::ContractLogs).unwrap(),
+            Some(checkpoint(None))
+        );
+    }
+
+    #[test]
+    fn assert_no_prune_regression() {
+        let checkpoint = |block_number| PruneCheckpoint {
+            block_number,
+            tx_number: None,
+            prune_mode: PruneMode::Full,
+        };
+        let before = vec![
+            (PruneSegment::Receipts, checkpoint(Some(10))),
+            (PruneSegment::Headers, checkpoint(Some(20))),
+            (PruneSegment::ContractLogs, checkpoint(Some(30))),
+        ];
+
+        // advanced, unchanged or newly added segments are not regressions
+        let after = vec![
+            (PruneSegment::Receipts, checkpoint(Some(15))),
+            (PruneSegment::Headers, checkpoint(Some(20))),
+            (PruneSegment::ContractLogs, checkpoint(Some(30))),
+            (PruneSegment::Transactions, checkpoint(Some(1))),
+        ];
+        assert_eq!(super::assert_no_prune_regression(&before, &after), Ok(()));
+
+        let after = vec![
+            (PruneSegment::Receipts, checkpoint(Some(15))),
+            (PruneSegment::Headers, checkpoint(Some(19))),
+            (PruneSegment::ContractLogs, checkpoint(None)),
+        ];
+        assert_eq!(
+            super::assert_no_prune_regression(&before, &after),
+            Err(vec![PruneSegment::ContractLogs, PruneSegment::Headers])
+        );
+    }
+
+    #[test]
+    fn prune_segments_by_mode() {
+        let store = MemoryPruneCheckpointStore::new();
+        let checkpoint =
+            |prune_mode| PruneCheckpoint { block_number: None, tx_number: None, prune_mode };
+        store.save_prune_checkpoint(PruneSegment::Receipts, checkpoint(PruneMode::Full)).unwrap();
+        store
+            .save_prune_checkpoint(PruneSegment::SenderRecovery, checkpoint(PruneMode::Full))
+            .unwrap();
+        store
+            .save_prune_checkpoint(
+                PruneSegment::AccountHistory,
+                checkpoint(PruneMode::Distance(64)),
+            )
+            .unwrap();
+        store
+            .save_prune_checkpoint(PruneSegment::StorageHistory, checkpoint(PruneMode::Before(100)))
+            .unwrap();
+
+        assert_eq!(
+            store.prune_segments_by_mode().unwrap(),
+            BTreeMap::from([
+                (PruneModeKind::Full, vec![PruneSegment::SenderRecovery, PruneSegment::Receipts]),
+                (PruneModeKind::Distance, vec![PruneSegment::AccountHistory]),
+                (PruneModeKind::Before, vec![PruneSegment::StorageHistory]),
+                (
+                    PruneModeKind::Unset,
+                    vec![
+                        PruneSegment::TransactionLookup,
+                        PruneSegment::ContractLogs,
+                        PruneSegment::Headers,
+                        PruneSegment::Transactions,
+                    ]
+                ),
+            ])
+        );
+    }
 }
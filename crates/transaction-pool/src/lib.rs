@@ -153,9 +153,15 @@
 use crate::{identifier::TransactionId, pool::PoolInner};
 use aquamarine as _;
 use reth_eth_wire_types::HandleMempoolData;
-use reth_primitives::{Address, BlobTransactionSidecar, PooledTransactionsElement, TxHash, U256};
+use reth_primitives::{
+    Address, BlobTransactionSidecar, BlockNumber, PooledTransactionsElement, TxHash, U256,
+};
 use reth_storage_api::StateProviderFactory;
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
 use tokio::sync::mpsc::Receiver;
 use tracing::{instrument, trace};
 
@@ -170,8 +176,10 @@ pub use crate::{
     error::PoolResult,
     ordering::{CoinbaseTipOrdering, Priority, TransactionOrdering},
     pool::{
-        blob_tx_priority, fee_delta, state::SubPool, AllTransactionsEvents, FullTransactionEvent,
-        TransactionEvent, TransactionEvents,
+        blob_tx_priority, fee_delta,
+        state::{QueuedReason, SubPool, TransactionPlacement},
+        AllTransactionsEvents, ChurnStats, FullTransactionEvent, TransactionEvent,
+        TransactionEvents,
     },
     traits::*,
     validate::{
@@ -470,10 +478,30 @@ where
         self.inner().get(tx_hash)
     }
 
+    fn transaction_placement(&self, tx_hash: &TxHash) -> Option<TransactionPlacement> {
+        self.inner().transaction_placement(tx_hash)
+    }
+
     fn get_all(&self, txs: Vec<TxHash>) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
         self.inner().get_all(txs)
     }
 
+    fn on_received_from_peer(&self, tx_hash: TxHash, peer: PeerId) {
+        self.inner().on_received_from_peer(tx_hash, peer)
+    }
+
+    fn transactions_received_from_peers(&self) -> HashMap<TxHash, PeerId> {
+        self.inner().transactions_received_from_peers()
+    }
+
+    fn transactions_first_eligible_blocks(&self) -> HashMap<TxHash, BlockNumber> {
+        self.inner().transactions_first_eligible_blocks()
+    }
+
+    fn churn_stats(&self, window: Duration) -> ChurnStats {
+        self.inner().churn_stats(window)
+    }
+
     fn on_propagated(&self, txs: PropagatedTransactions) {
         self.inner().on_propagated(txs)
     }
@@ -523,6 +551,10 @@ where
     ) -> Result<Vec<BlobTransactionSidecar>, BlobStoreError> {
         self.pool.blob_store().get_exact(tx_hashes)
     }
+
+    fn blob_store_size_hint(&self) -> Option<usize> {
+        self.pool.blob_store().data_size_hint()
+    }
 }
 
 impl<V, T, S> TransactionPoolExt for Pool<V, T, S>
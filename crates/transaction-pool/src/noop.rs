@@ -11,14 +11,20 @@ use crate::{
         TransactionListenerKind,
     },
     validate::ValidTransaction,
-    AllPoolTransactions, AllTransactionsEvents, BestTransactions, BlockInfo, EthPoolTransaction,
-    EthPooledTransaction, NewTransactionEvent, PoolResult, PoolSize, PoolTransaction,
-    PooledTransactionsElement, PropagatedTransactions, TransactionEvents, TransactionOrigin,
-    TransactionPool, TransactionValidationOutcome, TransactionValidator, ValidPoolTransaction,
+    AllPoolTransactions, AllTransactionsEvents, BestTransactions, BlockInfo, ChurnStats,
+    EthPoolTransaction, EthPooledTransaction, NewTransactionEvent, PeerId, PoolResult, PoolSize,
+    PoolTransaction, PooledTransactionsElement, PropagatedTransactions, TransactionEvents,
+    TransactionOrigin, TransactionPlacement, TransactionPool, TransactionValidationOutcome,
+    TransactionValidator, ValidPoolTransaction,
 };
 use reth_eth_wire_types::HandleMempoolData;
-use reth_primitives::{Address, BlobTransactionSidecar, TxHash, U256};
-use std::{collections::HashSet, marker::PhantomData, sync::Arc};
+use reth_primitives::{Address, BlobTransactionSidecar, BlockNumber, TxHash, U256};
+use std::{
+    collections::{HashMap, HashSet},
+    marker::PhantomData,
+    sync::Arc,
+    time::Duration,
+};
 use tokio::sync::{mpsc, mpsc::Receiver};
 
 /// A [`TransactionPool`] implementation that does nothing.
@@ -190,10 +196,28 @@ impl TransactionPool for NoopTransactionPool {
         None
     }
 
+    fn transaction_placement(&self, _tx_hash: &TxHash) -> Option<TransactionPlacement> {
+        None
+    }
+
     fn get_all(&self, _txs: Vec<TxHash>) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
         vec![]
     }
 
+    fn on_received_from_peer(&self, _tx_hash: TxHash, _peer: PeerId) {}
+
+    fn transactions_received_from_peers(&self) -> HashMap<TxHash, PeerId> {
+        HashMap::new()
+    }
+
+    fn transactions_first_eligible_blocks(&self) -> HashMap<TxHash, BlockNumber> {
+        HashMap::new()
+    }
+
+    fn churn_stats(&self, _window: Duration) -> ChurnStats {
+        ChurnStats::default()
+    }
+
     fn on_propagated(&self, _txs: PropagatedTransactions) {}
 
     fn get_transactions_by_sender(
@@ -242,6 +266,10 @@ impl TransactionPool for NoopTransactionPool {
         }
         Err(BlobStoreError::MissingSidecar(tx_hashes[0]))
     }
+
+    fn blob_store_size_hint(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// A [`TransactionValidator`] that does nothing.
@@ -0,0 +1,124 @@
+//! Tracks how many transactions entered and left the pool over time.
+
+use std::{
+    collections::VecDeque,
+    ops::AddAssign,
+    time::{Duration, Instant},
+};
+
+/// The time span covered by a single bucket of the [`ChurnTracker`].
+const CHURN_BUCKET_SPAN: Duration = Duration::from_secs(1);
+
+/// The longest window the [`ChurnTracker`] keeps counts for.
+pub const MAX_CHURN_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+/// Counts of transactions that entered or left the pool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChurnStats {
+    /// Transactions that were added to the pool without replacing another transaction.
+    pub new: u64,
+    /// Transactions that were added to the pool by replacing a transaction with the same sender
+    /// and nonce.
+    pub replaced: u64,
+    /// Transactions that left the pool because they were mined, discarded or removed.
+    pub removed: u64,
+}
+
+impl AddAssign for ChurnStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.new += rhs.new;
+        self.replaced += rhs.replaced;
+        self.removed += rhs.removed;
+    }
+}
+
+/// Rolling [`ChurnStats`] in buckets of one second, covering at most [`MAX_CHURN_WINDOW`].
+///
+/// The current time is passed in by the caller, so the windowing does not depend on the system
+/// clock.
+#[derive(Debug, Default)]
+pub(crate) struct ChurnTracker {
+    /// The start of each bucket and its counts, oldest first.
+    buckets: VecDeque<(Instant, ChurnStats)>,
+}
+
+impl ChurnTracker {
+    /// Adds the given counts at `now` and forgets buckets that are older than
+    /// [`MAX_CHURN_WINDOW`].
+    pub(crate) fn record(&mut self, now: Instant, stats: ChurnStats) {
+        match self.buckets.back_mut() {
+            Some((start, bucket)) if now.saturating_duration_since(*start) < CHURN_BUCKET_SPAN => {
+                *bucket += stats
+            }
+            _ => self.buckets.push_back((now, stats)),
+        }
+
+        while self
+            .buckets
+            .front()
+            .is_some_and(|(start, _)| now.saturating_duration_since(*start) > MAX_CHURN_WINDOW)
+        {
+            self.buckets.pop_front();
+        }
+    }
+
+    /// Returns the sum of all counts recorded within `window` before `now`.
+    ///
+    /// Counts are tracked per bucket, so the oldest counts may be up to one bucket older than the
+    /// window.
+    pub(crate) fn stats(&self, now: Instant, window: Duration) -> ChurnStats {
+        let window = window.min(MAX_CHURN_WINDOW);
+        let mut stats = ChurnStats::default();
+        for (start, bucket) in self.buckets.iter().rev() {
+            if now.saturating_duration_since(*start) > window {
+                break
+            }
+            stats += *bucket;
+        }
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn churn_stats_within_window() {
+        let start = Instant::now();
+        let mut tracker = ChurnTracker::default();
+        tracker.record(start, ChurnStats { new: 2, ..Default::default() });
+        tracker.record(
+            start + Duration::from_millis(500),
+            ChurnStats { replaced: 1, ..Default::default() },
+        );
+        tracker.record(
+            start + Duration::from_secs(10),
+            ChurnStats { removed: 3, ..Default::default() },
+        );
+
+        let now = start + Duration::from_secs(10);
+        assert_eq!(
+            tracker.stats(now, Duration::from_secs(5)),
+            ChurnStats { new: 0, replaced: 0, removed: 3 }
+        );
+        assert_eq!(
+            tracker.stats(now, Duration::from_secs(10)),
+            ChurnStats { new: 2, replaced: 1, removed: 3 }
+        );
+    }
+
+    #[test]
+    fn churn_buckets_are_pruned() {
+        let start = Instant::now();
+        let mut tracker = ChurnTracker::default();
+        tracker.record(start, ChurnStats { new: 1, ..Default::default() });
+        tracker.record(start + MAX_CHURN_WINDOW + Duration::from_secs(1), ChurnStats::default());
+
+        assert_eq!(tracker.buckets.len(), 1);
+        assert_eq!(
+            tracker.stats(start + MAX_CHURN_WINDOW, MAX_CHURN_WINDOW),
+            ChurnStats::default()
+        );
+    }
+}
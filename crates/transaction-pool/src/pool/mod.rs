@@ -70,12 +70,12 @@ use crate::{
     identifier::{SenderId, SenderIdentifiers, TransactionId},
     pool::{
         listener::PoolEventBroadcast,
-        state::SubPool,
+        state::{SubPool, TransactionPlacement},
         txpool::{SenderInfo, TxPool},
     },
     traits::{
-        AllPoolTransactions, BestTransactionsAttributes, BlockInfo, NewTransactionEvent, PoolSize,
-        PoolTransaction, PropagatedTransactions, TransactionOrigin,
+        AllPoolTransactions, BestTransactionsAttributes, BlockInfo, NewTransactionEvent, PeerId,
+        PoolSize, PoolTransaction, PropagatedTransactions, TransactionOrigin,
     },
     validate::{TransactionValidationOutcome, ValidPoolTransaction},
     CanonicalStateUpdate, ChangedAccount, PoolConfig, TransactionOrdering, TransactionValidator,
@@ -84,14 +84,14 @@ use best::BestTransactions;
 use parking_lot::{Mutex, RwLock, RwLockReadGuard};
 use reth_eth_wire_types::HandleMempoolData;
 use reth_primitives::{
-    Address, BlobTransaction, BlobTransactionSidecar, IntoRecoveredTransaction,
+    Address, BlobTransaction, BlobTransactionSidecar, BlockNumber, IntoRecoveredTransaction,
     PooledTransactionsElement, TransactionSigned, TxHash, B256,
 };
 use std::{
     collections::{HashMap, HashSet},
     fmt,
     sync::Arc,
-    time::Instant,
+    time::{Duration, Instant},
 };
 use tokio::sync::mpsc;
 use tracing::{debug, trace, warn};
@@ -99,12 +99,13 @@ mod events;
 use crate::{
     blobstore::BlobStore,
     metrics::BlobStoreMetrics,
-    pool::txpool::UpdateOutcome,
+    pool::{churn::ChurnTracker, txpool::UpdateOutcome},
     traits::{GetPooledTransactionLimit, NewBlobSidecar, TransactionListenerKind},
     validate::ValidTransaction,
 };
 pub use best::BestTransactionFilter;
 pub use blob::{blob_tx_priority, fee_delta};
+pub use churn::{ChurnStats, MAX_CHURN_WINDOW};
 pub use events::{FullTransactionEvent, TransactionEvent};
 pub use listener::{AllTransactionsEvents, TransactionEvents};
 pub use parked::{BasefeeOrd, ParkedOrd, ParkedPool, QueuedOrd};
@@ -112,6 +113,7 @@ pub use pending::PendingPool;
 
 mod best;
 mod blob;
+mod churn;
 mod listener;
 mod parked;
 pub(crate) mod pending;
@@ -152,6 +154,19 @@ where
     blob_transaction_sidecar_listener: Mutex<Vec<BlobTransactionSidecarListener>>,
     /// Metrics for the blob store
     blob_store_metrics: BlobStoreMetrics,
+    /// The peer each transaction was first received from.
+    ///
+    /// Entries of transactions that are no longer in the pool are pruned on every canonical state
+    /// change.
+    received_from: RwLock<HashMap<TxHash, PeerId>>,
+    /// The first block each transaction was eligible for inclusion in.
+    ///
+    /// Recorded when the transaction first enters the pending sub-pool, as the block after the
+    /// last block the pool has seen at that time. Entries of transactions that are no longer in
+    /// the pool are pruned on every canonical state change.
+    first_eligible_block: RwLock<HashMap<TxHash, BlockNumber>>,
+    /// Rolling counts of transactions that entered and left the pool.
+    churn: Mutex<ChurnTracker>,
 }
 
 // === impl PoolInner ===
@@ -175,6 +190,9 @@ where
             config,
             blob_store,
             blob_store_metrics: Default::default(),
+            received_from: Default::default(),
+            first_eligible_block: Default::default(),
+            churn: Default::default(),
         }
     }
 
@@ -391,6 +409,11 @@ where
 
         // notify listeners about updates
         self.notify_on_new_state(outcome);
+
+        // forget the source peers of transactions that are no longer in the pool
+        let pool = self.get_pool_data();
+        self.received_from.write().retain(|hash, _| pool.contains(hash));
+        self.first_eligible_block.write().retain(|hash, _| pool.contains(hash));
     }
 
     /// Performs account updates on the pool.
@@ -400,6 +423,8 @@ where
         let changed_senders = self.changed_senders(accounts.into_iter());
         let UpdateOutcome { promoted, discarded } =
             self.pool.write().update_accounts(changed_senders);
+        self.record_eligible(promoted.iter().map(|tx| tx.hash()));
+        self.record_churn(ChurnStats { removed: discarded.len() as u64, ..Default::default() });
         let mut listener = self.event_listener.write();
 
         promoted.iter().for_each(|tx| listener.pending(tx.hash(), None));
@@ -526,6 +551,7 @@ where
             return added
         }
 
+        self.record_churn(ChurnStats { removed: discarded.len() as u64, ..Default::default() });
         {
             let mut listener = self.event_listener.write();
             discarded.iter().for_each(|tx| listener.discarded(tx));
@@ -617,6 +643,12 @@ where
 
         let OnNewCanonicalStateOutcome { mined, promoted, discarded, block_hash } = outcome;
 
+        self.record_eligible(promoted.iter().map(|tx| tx.hash()));
+        self.record_churn(ChurnStats {
+            removed: (mined.len() + discarded.len()) as u64,
+            ..Default::default()
+        });
+
         // broadcast specific transaction events
         let mut listener = self.event_listener.write();
 
@@ -627,6 +659,22 @@ where
 
     /// Fire events for the newly added transaction if there are any.
     fn notify_event_listeners(&self, tx: &AddedTransaction<T::Transaction>) {
+        if let AddedTransaction::Pending(tx) = tx {
+            self.record_eligible(
+                std::iter::once(tx.transaction.hash())
+                    .chain(tx.promoted.iter().map(|tx| tx.hash())),
+            );
+        }
+        let (replaced, discarded) = match tx {
+            AddedTransaction::Pending(tx) => (tx.replaced.is_some(), tx.discarded.len()),
+            AddedTransaction::Parked { replaced, .. } => (replaced.is_some(), 0),
+        };
+        self.record_churn(ChurnStats {
+            new: u64::from(!replaced),
+            replaced: u64::from(replaced),
+            removed: discarded as u64,
+        });
+
         let mut listener = self.event_listener.write();
 
         match tx {
@@ -689,6 +737,7 @@ where
             return Vec::new()
         }
         let removed = self.pool.write().remove_transactions(hashes);
+        self.record_churn(ChurnStats { removed: removed.len() as u64, ..Default::default() });
 
         let mut listener = self.event_listener.write();
 
@@ -717,6 +766,11 @@ where
         self.get_pool_data().get(tx_hash)
     }
 
+    /// Returns the placement of the transaction with the given hash.
+    pub(crate) fn transaction_placement(&self, tx_hash: &TxHash) -> Option<TransactionPlacement> {
+        self.get_pool_data().transaction_placement(tx_hash)
+    }
+
     /// Returns all transactions of the address
     pub(crate) fn get_transactions_by_sender(
         &self,
@@ -747,6 +801,56 @@ where
         self.get_pool_data().get_all(txs).collect()
     }
 
+    /// Records the peer the transaction was first received from.
+    pub(crate) fn on_received_from_peer(&self, tx_hash: TxHash, peer: PeerId) {
+        self.received_from.write().entry(tx_hash).or_insert(peer);
+    }
+
+    /// Records the next block as the first eligible block of the given transactions that just
+    /// entered the pending sub-pool, unless they were already eligible before.
+    fn record_eligible<'a>(&self, hashes: impl IntoIterator<Item = &'a TxHash>) {
+        let block = self.block_info().last_seen_block_number + 1;
+        let mut first_eligible_block = self.first_eligible_block.write();
+        for hash in hashes {
+            first_eligible_block.entry(*hash).or_insert(block);
+        }
+    }
+
+    /// Adds the given counts to the churn tracked at the current time.
+    fn record_churn(&self, stats: ChurnStats) {
+        if stats != ChurnStats::default() {
+            self.churn.lock().record(Instant::now(), stats);
+        }
+    }
+
+    /// Returns the number of transactions that entered and left the pool within the window.
+    pub(crate) fn churn_stats(&self, window: Duration) -> ChurnStats {
+        self.churn.lock().stats(Instant::now(), window)
+    }
+
+    /// Returns the first block each of the currently pooled transactions was eligible for
+    /// inclusion in.
+    pub(crate) fn transactions_first_eligible_blocks(&self) -> HashMap<TxHash, BlockNumber> {
+        let pool = self.get_pool_data();
+        self.first_eligible_block
+            .read()
+            .iter()
+            .filter(|(hash, _)| pool.contains(hash))
+            .map(|(hash, block)| (*hash, *block))
+            .collect()
+    }
+
+    /// Returns the peer each of the currently pooled transactions was first received from.
+    pub(crate) fn transactions_received_from_peers(&self) -> HashMap<TxHash, PeerId> {
+        let pool = self.get_pool_data();
+        self.received_from
+            .read()
+            .iter()
+            .filter(|(hash, _)| pool.contains(hash))
+            .map(|(hash, peer)| (*hash, *peer))
+            .collect()
+    }
+
     /// Notify about propagated transactions.
     pub(crate) fn on_propagated(&self, txs: PropagatedTransactions) {
         if txs.0.is_empty() {
@@ -1136,10 +1240,11 @@ mod tests {
         blobstore::{BlobStore, InMemoryBlobStore},
         test_utils::{MockTransaction, TestPoolBuilder},
         validate::ValidTransaction,
-        BlockInfo, PoolConfig, SubPoolLimit, TransactionOrigin, TransactionValidationOutcome, U256,
+        BlockInfo, CanonicalStateUpdate, ChurnStats, PoolConfig, SubPoolLimit, TransactionOrigin,
+        TransactionValidationOutcome, U256,
     };
-    use reth_primitives::{kzg::Blob, transaction::generate_blob_sidecar};
-    use std::{fs, path::PathBuf};
+    use reth_primitives::{kzg::Blob, transaction::generate_blob_sidecar, SealedBlock};
+    use std::{fs, path::PathBuf, time::Duration};
 
     #[test]
     fn test_discard_blobs_on_blob_tx_eviction() {
@@ -1220,4 +1325,39 @@ mod tests {
         // Assert that the pool's blob store matches the expected blob store.
         assert_eq!(*test_pool.blob_store(), blob_store);
     }
+
+    #[test]
+    fn churn_stats_count_mined_transactions() {
+        let test_pool = &TestPoolBuilder::default().pool;
+
+        let transactions = [MockTransaction::eip1559(), MockTransaction::eip1559()];
+        for tx in transactions.clone() {
+            test_pool
+                .add_transaction(
+                    TransactionOrigin::External,
+                    TransactionValidationOutcome::Valid {
+                        balance: U256::from(1_000_000_000_000u64),
+                        state_nonce: 0,
+                        transaction: ValidTransaction::Valid(tx),
+                        propagate: true,
+                    },
+                )
+                .unwrap();
+        }
+
+        let new_tip = SealedBlock::default();
+        test_pool.on_canonical_state_change(CanonicalStateUpdate {
+            new_tip: &new_tip,
+            pending_block_base_fee: 0,
+            pending_block_blob_fee: None,
+            changed_accounts: Vec::new(),
+            mined_transactions: vec![transactions[0].get_hash()],
+        });
+
+        assert_eq!(test_pool.size().total, 1);
+        assert_eq!(
+            test_pool.churn_stats(Duration::from_secs(60)),
+            ChurnStats { new: 2, replaced: 0, removed: 1 }
+        );
+    }
 }
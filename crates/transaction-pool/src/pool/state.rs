@@ -70,6 +70,60 @@ impl TxState {
     pub(crate) const fn has_nonce_gap(&self) -> bool {
         !self.intersects(Self::NO_NONCE_GAPS)
     }
+
+    /// Returns the requirements of the pending sub-pool that are not met by this state.
+    ///
+    /// Parked ancestors and the balance are only evaluated for transactions without a nonce gap,
+    /// so they are not reported for transactions with a nonce gap.
+    pub(crate) fn queued_reasons(&self) -> Vec<QueuedReason> {
+        let mut reasons = Vec::new();
+        if self.has_nonce_gap() {
+            reasons.push(QueuedReason::NonceGap);
+        } else {
+            if !self.contains(Self::NO_PARKED_ANCESTORS) {
+                reasons.push(QueuedReason::ParkedAncestor);
+            }
+            if !self.contains(Self::ENOUGH_BALANCE) {
+                reasons.push(QueuedReason::InsufficientBalance);
+            }
+        }
+        if !self.contains(Self::NOT_TOO_MUCH_GAS) {
+            reasons.push(QueuedReason::ExceedsBlockGasLimit);
+        }
+        if !self.contains(Self::ENOUGH_FEE_CAP_BLOCK) {
+            reasons.push(QueuedReason::InsufficientBaseFee);
+        }
+        if self.is_blob() && !self.contains(Self::ENOUGH_BLOB_FEE_CAP_BLOCK) {
+            reasons.push(QueuedReason::InsufficientBlobFee);
+        }
+        reasons
+    }
+}
+
+/// A requirement of the pending sub-pool that a parked transaction does not meet.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum QueuedReason {
+    /// A transaction with a lower nonce of the same sender is missing.
+    NonceGap,
+    /// A transaction with a lower nonce of the same sender is not pending.
+    ParkedAncestor,
+    /// The sender can't cover the cost of this transaction and all prior transactions.
+    InsufficientBalance,
+    /// The gas limit of the transaction exceeds the block gas limit.
+    ExceedsBlockGasLimit,
+    /// The max fee per gas is below the base fee of the pending block.
+    InsufficientBaseFee,
+    /// The max fee per blob gas is below the blob fee of the pending block.
+    InsufficientBlobFee,
+}
+
+/// The placement of a pooled transaction, as classified by the pool.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TransactionPlacement {
+    /// The sub-pool that contains the transaction.
+    pub subpool: SubPool,
+    /// Why the transaction is not pending, empty for pending transactions.
+    pub queued_reasons: Vec<QueuedReason>,
 }
 
 /// Identifier for the transaction Sub-pool
@@ -189,6 +243,24 @@ mod tests {
         assert!(state.is_pending());
     }
 
+    #[test]
+    fn test_queued_reasons() {
+        assert!(TxState::PENDING_POOL_BITS.queued_reasons().is_empty());
+
+        let state = TxState::NOT_TOO_MUCH_GAS | TxState::ENOUGH_BALANCE;
+        assert_eq!(
+            state.queued_reasons(),
+            vec![QueuedReason::NonceGap, QueuedReason::InsufficientBaseFee]
+        );
+
+        let mut state = TxState::PENDING_POOL_BITS;
+        state.remove(TxState::NO_PARKED_ANCESTORS | TxState::ENOUGH_BALANCE);
+        assert_eq!(
+            state.queued_reasons(),
+            vec![QueuedReason::ParkedAncestor, QueuedReason::InsufficientBalance]
+        );
+    }
+
     #[test]
     fn test_blob() {
         let mut state = TxState::PENDING_POOL_BITS;
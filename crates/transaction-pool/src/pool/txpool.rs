@@ -10,7 +10,7 @@ use crate::{
         blob::BlobTransactions,
         parked::{BasefeeOrd, ParkedPool, QueuedOrd},
         pending::PendingPool,
-        state::{SubPool, TxState},
+        state::{SubPool, TransactionPlacement, TxState},
         update::{Destination, PoolUpdate},
         AddedPendingTransaction, AddedTransaction, OnNewCanonicalStateOutcome,
     },
@@ -365,6 +365,16 @@ impl<T: TransactionOrdering> TxPool<T> {
         self.all_transactions.by_hash.get(tx_hash).cloned()
     }
 
+    /// Returns the sub-pool of the transaction with the given hash and why it is not pending.
+    pub(crate) fn transaction_placement(&self, tx_hash: &TxHash) -> Option<TransactionPlacement> {
+        let id = self.all_transactions.by_hash.get(tx_hash)?.transaction_id;
+        let tx = self.all_transactions.txs.get(&id)?;
+        Some(TransactionPlacement {
+            subpool: tx.subpool,
+            queued_reasons: tx.state.queued_reasons(),
+        })
+    }
+
     /// Returns transactions for the multiple given hashes, if they exist.
     pub(crate) fn get_all(
         &self,
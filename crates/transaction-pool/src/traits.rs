@@ -3,7 +3,10 @@
 use crate::{
     blobstore::BlobStoreError,
     error::PoolResult,
-    pool::{state::SubPool, BestTransactionFilter, TransactionEvents},
+    pool::{
+        state::{SubPool, TransactionPlacement},
+        BestTransactionFilter, ChurnStats, TransactionEvents,
+    },
     validate::ValidPoolTransaction,
     AllTransactionsEvents,
 };
@@ -11,7 +14,7 @@ use futures_util::{ready, Stream};
 use reth_eth_wire_types::HandleMempoolData;
 use reth_primitives::{
     kzg::KzgSettings, transaction::TryFromRecoveredTransactionError, AccessList, Address,
-    BlobTransactionSidecar, BlobTransactionValidationError, PooledTransactionsElement,
+    BlobTransactionSidecar, BlobTransactionValidationError, BlockNumber, PooledTransactionsElement,
     PooledTransactionsElementEcRecovered, SealedBlock, Transaction, TransactionSignedEcRecovered,
     TxHash, TxKind, B256, EIP1559_TX_TYPE_ID, EIP4844_TX_TYPE_ID, EIP7702_TX_TYPE_ID, U256,
 };
@@ -24,6 +27,7 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
+    time::Duration,
 };
 use tokio::sync::mpsc::Receiver;
 
@@ -315,11 +319,41 @@ pub trait TransactionPool: Send + Sync + Clone {
     /// Returns the transaction for the given hash.
     fn get(&self, tx_hash: &TxHash) -> Option<Arc<ValidPoolTransaction<Self::Transaction>>>;
 
+    /// Returns the sub-pool the transaction with the given hash is in and which requirements of
+    /// the pending sub-pool it does not meet, or `None` if it is not in the pool.
+    fn transaction_placement(&self, tx_hash: &TxHash) -> Option<TransactionPlacement>;
+
     /// Returns all transactions objects for the given hashes.
     ///
     /// Caution: This in case of blob transactions, this does not include the sidecar.
     fn get_all(&self, txs: Vec<TxHash>) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>>;
 
+    /// Notify the pool that the transaction with the given hash was received from the given peer.
+    ///
+    /// This should only be called after the transaction was successfully imported into the pool.
+    /// Only the first peer a transaction was received from is recorded.
+    ///
+    /// Consumer: P2P
+    fn on_received_from_peer(&self, tx_hash: TxHash, peer: PeerId);
+
+    /// Returns the peer each of the currently pooled transactions was first received from.
+    ///
+    /// Transactions that were not received over the network are not included.
+    fn transactions_received_from_peers(&self) -> HashMap<TxHash, PeerId>;
+
+    /// Returns the first block each of the currently pooled transactions was eligible for
+    /// inclusion in.
+    ///
+    /// This is the block after the last block the pool had seen when the transaction first
+    /// entered the pending sub-pool. Transactions that were never pending are not included.
+    fn transactions_first_eligible_blocks(&self) -> HashMap<TxHash, BlockNumber>;
+
+    /// Returns how many transactions were added, replaced and removed within the given window
+    /// before now.
+    ///
+    /// Windows longer than [`MAX_CHURN_WINDOW`](crate::pool::MAX_CHURN_WINDOW) are capped.
+    fn churn_stats(&self, window: Duration) -> ChurnStats;
+
     /// Notify the pool about transactions that are propagated to peers.
     ///
     /// Consumer: P2P
@@ -384,6 +418,9 @@ pub trait TransactionPool: Send + Sync + Clone {
         &self,
         tx_hashes: Vec<TxHash>,
     ) -> Result<Vec<BlobTransactionSidecar>, BlobStoreError>;
+
+    /// Returns the total size of all sidecars in the blob store, if the store can provide it.
+    fn blob_store_size_hint(&self) -> Option<usize>;
 }
 
 /// Extension for [TransactionPool] trait that allows to set the current block info.